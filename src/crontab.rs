@@ -0,0 +1,237 @@
+//! Parsing of crontab files into recurring series.
+//!
+//! A [`Crontab`] holds the entries of a system crontab file like `/etc/crontab`. Each
+//! [`CrontabEntry`] consists of a [`Cron`] schedule, the user the command runs as and the command
+//! itself. Entries can be turned into a [`Series`] via [`CrontabEntry::series`].
+//!
+//! # Example
+//!
+//! ```
+//! use jiff::civil::date;
+//! use recurring::crontab::Crontab;
+//!
+//! let crontab: Crontab = "
+//! ## Run the backup every night.
+//! SHELL=/bin/sh
+//! 30 2 * * * root /usr/local/bin/backup --full
+//! @hourly    www  /usr/bin/php /var/www/cron.php
+//! ".parse()?;
+//!
+//! let backup = &crontab.entries()[0];
+//! assert_eq!(backup.user(), "root");
+//! assert_eq!(backup.command(), "/usr/local/bin/backup --full");
+//! assert_eq!(backup.env(), [("SHELL".into(), "/bin/sh".into())]);
+//!
+//! let series = backup.series(date(2025, 1, 1).at(0, 0, 0, 0));
+//! assert_eq!(series.first().unwrap().start(), date(2025, 1, 1).at(2, 30, 0, 0));
+//! # Ok::<(), Box<dyn core::error::Error>>(())
+//! ```
+
+use crate::error::{Error, err};
+use crate::pattern::Cron;
+use crate::series::Series;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use jiff::civil::DateTime;
+
+/// The entries and environment variables of a system crontab file.
+///
+/// See the [module documentation][self] for an example.
+#[derive(Debug, Clone, Default)]
+pub struct Crontab {
+    env: Vec<(String, String)>,
+    entries: Vec<CrontabEntry>,
+}
+
+impl Crontab {
+    /// Parses a system crontab file like `/etc/crontab`.
+    ///
+    /// Each line of the input is one of:
+    ///
+    /// - An empty line or a comment starting with `#`, which are ignored.
+    /// - An environment variable assignment like `SHELL=/bin/sh` or `MAILTO = "ops"`. Values may
+    ///   be enclosed in single or double quotes. Assignments apply to all entries below them.
+    /// - An entry consisting of a five-field cron expression (see [`Cron::parse`]) or a macro
+    ///   like `@daily` (see [`CronMacro`][crate::pattern::CronMacro]), followed by the user and
+    ///   the command. The command extends to the end of the line and is not interpreted, i.e.
+    ///   `%` characters are kept as is.
+    ///
+    /// The `@reboot` macro is not supported because it has no schedule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error containing the line number if a line can't be parsed.
+    pub fn parse(input: &str) -> Result<Crontab, Error> {
+        let mut crontab = Crontab::default();
+
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let number = index + 1;
+
+            if let Some((name, value)) = parse_env(line) {
+                crontab.env.retain(|(existing, _)| existing != name);
+                crontab.env.push((name.to_string(), value.to_string()));
+                continue;
+            }
+
+            let entry = CrontabEntry::parse(line, number, &crontab.env)
+                .map_err(|err| Error::parse_line("crontab entry", number, err))?;
+            crontab.entries.push(entry);
+        }
+
+        Ok(crontab)
+    }
+
+    /// Returns the environment variables set at the end of the crontab file.
+    pub fn env(&self) -> &[(String, String)] {
+        &self.env
+    }
+
+    /// Returns the entries of the crontab file in the order they appear in.
+    pub fn entries(&self) -> &[CrontabEntry] {
+        &self.entries
+    }
+}
+
+impl FromStr for Crontab {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Crontab::parse(s)
+    }
+}
+
+/// A single entry of a [`Crontab`].
+#[derive(Debug, Clone)]
+pub struct CrontabEntry {
+    cron: Cron,
+    user: String,
+    command: String,
+    line: usize,
+    env: Vec<(String, String)>,
+}
+
+impl CrontabEntry {
+    fn parse(line: &str, number: usize, env: &[(String, String)]) -> Result<Self, Error> {
+        let (schedule, rest) = if line.starts_with('@') {
+            split_fields(line, 1)
+        } else {
+            split_fields(line, 5)
+        }
+        .ok_or_else(|| err!("expected a schedule followed by a user and a command"))?;
+
+        if schedule.eq_ignore_ascii_case("@reboot") {
+            return Err(err!("@reboot is not supported"));
+        }
+
+        let cron = Cron::parse(schedule)?;
+
+        let (user, command) = split_fields(rest, 1)
+            .filter(|(_, command)| !command.is_empty())
+            .ok_or_else(|| err!("expected a user and a command after the schedule"))?;
+
+        Ok(CrontabEntry {
+            cron,
+            user: user.to_string(),
+            command: command.to_string(),
+            line: number,
+            env: env.to_vec(),
+        })
+    }
+
+    /// Returns the schedule of the entry.
+    pub fn cron(&self) -> &Cron {
+        &self.cron
+    }
+
+    /// Returns the user the command runs as.
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// Returns the command.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Returns the (1-based) line number of the entry within the crontab file.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the environment variables which were set before the entry.
+    pub fn env(&self) -> &[(String, String)] {
+        &self.env
+    }
+
+    /// Creates a series of the entry's schedule starting at `start`.
+    ///
+    /// The fallible version of this method is [`CrontabEntry::try_series`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is `DateTime::MAX`.
+    pub fn series(&self, start: DateTime) -> Series<Cron> {
+        Series::new(start.., self.cron.clone())
+    }
+
+    /// Creates a series of the entry's schedule starting at `start`.
+    ///
+    /// The panicking version of this method is [`CrontabEntry::series`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `start` is `DateTime::MAX`.
+    pub fn try_series(&self, start: DateTime) -> Result<Series<Cron>, Error> {
+        Series::try_new(start.., self.cron.clone())
+    }
+}
+
+/// Parses an environment variable assignment like `NAME=value`.
+fn parse_env(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.split_once('=')?;
+    let name = name.trim();
+
+    let is_name = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !is_name {
+        return None;
+    }
+
+    let value = value.trim();
+    let unquoted = ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote));
+
+    Some((name, unquoted.unwrap_or(value)))
+}
+
+/// Splits `count` whitespace-separated fields off the start of `line`.
+///
+/// Returns the fields including the whitespace between them, and the rest of the line with
+/// leading whitespace removed.
+fn split_fields(line: &str, count: usize) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let mut end = 0;
+
+    for _ in 0..count {
+        let rest = &line[end..];
+        let start = end + (rest.len() - rest.trim_start().len());
+        if start == line.len() {
+            return None;
+        }
+
+        end = line[start..]
+            .find(char::is_whitespace)
+            .map_or(line.len(), |len| start + len);
+    }
+
+    Some((&line[..end], line[end..].trim_start()))
+}
@@ -51,6 +51,24 @@ impl Error {
         )))
     }
 
+    /// Creates a new error indicating that parsing `what` failed at the (1-based) character
+    /// `position` of the input.
+    #[inline(never)]
+    #[cold]
+    pub(crate) fn parse(what: &'static str, position: usize, err: Error) -> Error {
+        let location = Location::Position(position);
+        Error::from(ErrorKind::Parse(ParseError::new(what, location, err)))
+    }
+
+    /// Creates a new error indicating that parsing `what` failed on the (1-based) `line` of the
+    /// input.
+    #[inline(never)]
+    #[cold]
+    pub(crate) fn parse_line(what: &'static str, line: usize, err: Error) -> Error {
+        let location = Location::Line(line);
+        Error::from(ErrorKind::Parse(ParseError::new(what, location, err)))
+    }
+
     /// Creates a new error from a `jiff` error.
     #[inline(never)]
     #[cold]
@@ -98,6 +116,8 @@ enum ErrorKind {
     /// An error indicating that the end of a datetime range is not strictly greater than its
     /// start.
     DateTimeRange(DateTimeRangeError),
+    /// An error that occurs when parsing an input string fails at a certain position.
+    Parse(ParseError),
     /// An error produced by fallible operations on `jiff` types.
     Jiff(JiffError),
 }
@@ -108,6 +128,7 @@ impl fmt::Display for ErrorKind {
             ErrorKind::Adhoc(ref adhoc) => fmt::Display::fmt(adhoc, f),
             ErrorKind::Range(ref range) => fmt::Display::fmt(range, f),
             ErrorKind::DateTimeRange(ref range) => fmt::Display::fmt(range, f),
+            ErrorKind::Parse(ref parse) => fmt::Display::fmt(parse, f),
             ErrorKind::Jiff(ref jiff) => fmt::Display::fmt(jiff, f),
         }
     }
@@ -206,3 +227,58 @@ impl fmt::Display for DateTimeRangeError {
         )
     }
 }
+
+/// An error that occurs when parsing an input string fails at a certain position.
+#[derive(Debug, Clone)]
+struct ParseError {
+    what: &'static str,
+    location: Location,
+    err: Error,
+}
+
+impl ParseError {
+    /// Creates a new error indicating that parsing `what` failed at `location`.
+    fn new(what: &'static str, location: Location, err: Error) -> ParseError {
+        ParseError {
+            what,
+            location,
+            err,
+        }
+    }
+}
+
+/// The location within an input string where parsing failed.
+#[derive(Debug, Clone, Copy)]
+enum Location {
+    /// The (1-based) character position.
+    Position(usize),
+    /// The (1-based) line.
+    Line(usize),
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Location::Position(position) => write!(f, "at position {position}"),
+            Location::Line(line) => write!(f, "on line {line}"),
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid {what} {location}: {err}",
+            what = self.what,
+            location = self.location,
+            err = self.err
+        )
+    }
+}
@@ -6,6 +6,7 @@
 
 extern crate alloc;
 
+pub mod crontab;
 mod error;
 mod event;
 pub mod pattern;
@@ -17,7 +18,7 @@ pub use error::Error;
 pub use event::Event;
 use jiff::civil::{Date, DateTime, time};
 use jiff::{ToSpan, Zoned};
-use pattern::Combined;
+use pattern::{Combined, Except, Intersect};
 pub use range::DateTimeRange;
 #[doc(inline)]
 pub use series::Series;
@@ -32,7 +33,9 @@ mod private {
 /// to build a new series of recurring events.
 ///
 /// Since values implementing this trait must uphold some invariants to ensure correctness it is
-/// sealed to prevent implementing it outside of this crate.
+/// sealed to prevent implementing it outside of this crate. To define your own recurrence
+/// patterns, implement [`CustomPattern`][pattern::CustomPattern] instead, which documents these
+/// invariants, and wrap the value using [`pattern::custom`].
 ///
 /// There is usually no need to interact with this trait directly. Use the functionality provided
 /// by [`Series`] instead because it is more convenient.
@@ -87,6 +90,46 @@ pub trait Combine: Pattern + Sized {
     fn and<P: Pattern>(self, other: P) -> Combined<Self, P> {
         Combined::new(self, other)
     }
+
+    /// Intersect `Self` with another `Pattern`.
+    ///
+    /// The resulting pattern only produces events which are produced by both patterns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::Weekday;
+    /// use recurring::{Combine, pattern::{cron, minutely}};
+    ///
+    /// let business_hours = cron()
+    ///     .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+    ///     .hours(9..=16);
+    /// let every_quarter_hour_during_business_hours = minutely(15).intersect(business_hours);
+    /// ```
+    #[must_use]
+    fn intersect<P: Pattern>(self, other: P) -> Intersect<Self, P> {
+        Intersect::new(self, other)
+    }
+
+    /// Remove the events of another `Pattern` from `Self`.
+    ///
+    /// The resulting pattern only produces events of `Self` which are not produced by
+    /// `exclusion`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::{Combine, pattern::cron};
+    ///
+    /// let daily_at_noon = cron().hour(12).minute(0).second(0);
+    /// let christmas = cron().month(12).day(25);
+    ///
+    /// let daily_at_noon_except_christmas = daily_at_noon.except(christmas);
+    /// ```
+    #[must_use]
+    fn except<X: Pattern>(self, exclusion: X) -> Except<Self, X> {
+        Except::new(self, exclusion)
+    }
 }
 
 impl<T: Pattern> Combine for T {}
@@ -0,0 +1,328 @@
+use crate::pattern::{
+    Combined, Cron, Daily, Except, Explicit, Intersect, Interval, Shift, Union, Weekly,
+};
+use crate::{DateTimeRange, Pattern, private};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::fmt;
+use jiff::Span;
+use jiff::civil::DateTime;
+
+/// A type-erased recurrence pattern.
+///
+/// `AnyPattern` wraps any value implementing [`Pattern`], which allows choosing patterns at
+/// runtime and storing series with different patterns in a single collection. Cloning an
+/// `AnyPattern` is cheap because the wrapped pattern is shared.
+///
+/// If only the built-in patterns are needed, consider using [`BuiltinPattern`] instead, which
+/// avoids dynamic dispatch.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use recurring::{Combine, Series, pattern::{AnyPattern, cron, daily, hourly}};
+///
+/// let start = date(2025, 1, 1).at(0, 0, 0, 0);
+///
+/// let series: Vec<Series<AnyPattern>> = vec![
+///     Series::new(start.., AnyPattern::new(hourly(2))),
+///     Series::new(start.., AnyPattern::new(daily(1).and(cron().hour(12).minute(0).second(0)))),
+/// ];
+/// ```
+#[derive(Clone)]
+pub struct AnyPattern(Arc<dyn ErasedPattern + Send + Sync>);
+
+impl AnyPattern {
+    /// Create a new `AnyPattern` from a recurrence pattern.
+    pub fn new<P>(pattern: P) -> AnyPattern
+    where
+        P: Pattern + Send + Sync + 'static,
+    {
+        AnyPattern(Arc::new(pattern))
+    }
+}
+
+impl fmt::Debug for AnyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnyPattern").finish_non_exhaustive()
+    }
+}
+
+impl Pattern for AnyPattern {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0.erased_next_after(instant, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0.erased_previous_before(instant, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0.erased_closest_to(instant, range)
+    }
+}
+
+impl private::Sealed for AnyPattern {}
+
+/// An object-safe version of [`Pattern`], which is not object-safe because it requires `Clone`.
+trait ErasedPattern {
+    fn erased_next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime>;
+
+    fn erased_previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime>;
+
+    fn erased_closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime>;
+}
+
+impl<P: Pattern> ErasedPattern for P {
+    fn erased_next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        Pattern::next_after(self, instant, range)
+    }
+
+    fn erased_previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        Pattern::previous_before(self, instant, range)
+    }
+
+    fn erased_closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        Pattern::closest_to(self, instant, range)
+    }
+}
+
+/// One of the recurrence patterns provided by this crate.
+///
+/// This allows storing series with different built-in patterns in a single collection without
+/// type erasure. Each pattern type converts into the corresponding variant via `From`.
+///
+/// [`Filter`][crate::pattern::Filter] has no variant because it holds an arbitrary closure, and
+/// neither do user-defined [`Custom`][crate::pattern::Custom] patterns. Use [`AnyPattern`] for
+/// collections containing those.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use recurring::{Series, pattern::{BuiltinPattern, cron, daily, hourly}};
+///
+/// let start = date(2025, 1, 1).at(0, 0, 0, 0);
+///
+/// let patterns: Vec<BuiltinPattern> = vec![
+///     hourly(2).into(),
+///     daily(1).into(),
+///     BuiltinPattern::combined(cron().hour(12).minute(0).second(0), hourly(6)),
+/// ];
+///
+/// let series: Vec<Series<BuiltinPattern>> = patterns
+///     .into_iter()
+///     .map(|pattern| Series::new(start.., pattern))
+///     .collect();
+/// ```
+#[derive(Debug, Clone)]
+pub enum BuiltinPattern {
+    /// A [`Cron`] pattern.
+    Cron(Cron),
+    /// An [`Interval`] pattern.
+    Interval(Interval),
+    /// A [`Daily`] pattern.
+    Daily(Daily),
+    /// A [`Weekly`] pattern.
+    Weekly(Weekly),
+    /// A combination of two built-in patterns.
+    Combined(Box<Combined<BuiltinPattern, BuiltinPattern>>),
+    /// A union of any number of built-in patterns.
+    Union(Union<BuiltinPattern>),
+    /// An intersection of two built-in patterns.
+    Intersect(Box<Intersect<BuiltinPattern, BuiltinPattern>>),
+    /// A built-in pattern with the events of another built-in pattern removed.
+    Except(Box<Except<BuiltinPattern, BuiltinPattern>>),
+    /// An [`Explicit`] pattern.
+    Explicit(Explicit),
+    /// A built-in pattern with every event moved by a fixed offset.
+    Shift(Box<Shift<BuiltinPattern>>),
+}
+
+impl BuiltinPattern {
+    /// Create a `BuiltinPattern` combining two built-in patterns.
+    ///
+    /// This is the equivalent of [`.and()`][crate::Combine::and] for built-in patterns.
+    pub fn combined<L, R>(left: L, right: R) -> BuiltinPattern
+    where
+        L: Into<BuiltinPattern>,
+        R: Into<BuiltinPattern>,
+    {
+        let combined = Combined::new(left.into(), right.into());
+        BuiltinPattern::Combined(Box::new(combined))
+    }
+
+    /// Create a `BuiltinPattern` from the union of any number of built-in patterns.
+    ///
+    /// This is the equivalent of [`union()`][crate::pattern::union()] for built-in patterns.
+    pub fn union<P, I>(patterns: I) -> BuiltinPattern
+    where
+        P: Into<BuiltinPattern>,
+        I: IntoIterator<Item = P>,
+    {
+        BuiltinPattern::Union(patterns.into_iter().map(Into::into).collect())
+    }
+
+    /// Create a `BuiltinPattern` from the intersection of two built-in patterns.
+    pub fn intersect<L, R>(left: L, right: R) -> BuiltinPattern
+    where
+        L: Into<BuiltinPattern>,
+        R: Into<BuiltinPattern>,
+    {
+        let intersect = Intersect::new(left.into(), right.into());
+        BuiltinPattern::Intersect(Box::new(intersect))
+    }
+
+    /// Create a `BuiltinPattern` from a built-in pattern with the events of another built-in
+    /// pattern removed.
+    pub fn except<P, X>(pattern: P, exclusion: X) -> BuiltinPattern
+    where
+        P: Into<BuiltinPattern>,
+        X: Into<BuiltinPattern>,
+    {
+        let except = Except::new(pattern.into(), exclusion.into());
+        BuiltinPattern::Except(Box::new(except))
+    }
+
+    /// Create a `BuiltinPattern` from a built-in pattern with every event moved by `offset`.
+    pub fn shift<P: Into<BuiltinPattern>>(pattern: P, offset: Span) -> BuiltinPattern {
+        let shift = Shift::new(pattern.into(), offset);
+        BuiltinPattern::Shift(Box::new(shift))
+    }
+}
+
+impl Pattern for BuiltinPattern {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        match self {
+            BuiltinPattern::Cron(cron) => cron.next_after(instant, range),
+            BuiltinPattern::Interval(interval) => interval.next_after(instant, range),
+            BuiltinPattern::Daily(daily) => daily.next_after(instant, range),
+            BuiltinPattern::Weekly(weekly) => weekly.next_after(instant, range),
+            BuiltinPattern::Combined(combined) => combined.next_after(instant, range),
+            BuiltinPattern::Union(union) => union.next_after(instant, range),
+            BuiltinPattern::Intersect(intersect) => intersect.next_after(instant, range),
+            BuiltinPattern::Except(except) => except.next_after(instant, range),
+            BuiltinPattern::Explicit(explicit) => explicit.next_after(instant, range),
+            BuiltinPattern::Shift(shift) => shift.next_after(instant, range),
+        }
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        match self {
+            BuiltinPattern::Cron(cron) => cron.previous_before(instant, range),
+            BuiltinPattern::Interval(interval) => interval.previous_before(instant, range),
+            BuiltinPattern::Daily(daily) => daily.previous_before(instant, range),
+            BuiltinPattern::Weekly(weekly) => weekly.previous_before(instant, range),
+            BuiltinPattern::Combined(combined) => combined.previous_before(instant, range),
+            BuiltinPattern::Union(union) => union.previous_before(instant, range),
+            BuiltinPattern::Intersect(intersect) => intersect.previous_before(instant, range),
+            BuiltinPattern::Except(except) => except.previous_before(instant, range),
+            BuiltinPattern::Explicit(explicit) => explicit.previous_before(instant, range),
+            BuiltinPattern::Shift(shift) => shift.previous_before(instant, range),
+        }
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        match self {
+            BuiltinPattern::Cron(cron) => cron.closest_to(instant, range),
+            BuiltinPattern::Interval(interval) => interval.closest_to(instant, range),
+            BuiltinPattern::Daily(daily) => daily.closest_to(instant, range),
+            BuiltinPattern::Weekly(weekly) => weekly.closest_to(instant, range),
+            BuiltinPattern::Combined(combined) => combined.closest_to(instant, range),
+            BuiltinPattern::Union(union) => union.closest_to(instant, range),
+            BuiltinPattern::Intersect(intersect) => intersect.closest_to(instant, range),
+            BuiltinPattern::Except(except) => except.closest_to(instant, range),
+            BuiltinPattern::Explicit(explicit) => explicit.closest_to(instant, range),
+            BuiltinPattern::Shift(shift) => shift.closest_to(instant, range),
+        }
+    }
+}
+
+impl private::Sealed for BuiltinPattern {}
+
+impl From<Cron> for BuiltinPattern {
+    fn from(cron: Cron) -> Self {
+        BuiltinPattern::Cron(cron)
+    }
+}
+
+impl From<Interval> for BuiltinPattern {
+    fn from(interval: Interval) -> Self {
+        BuiltinPattern::Interval(interval)
+    }
+}
+
+impl From<Daily> for BuiltinPattern {
+    fn from(daily: Daily) -> Self {
+        BuiltinPattern::Daily(daily)
+    }
+}
+
+impl From<Weekly> for BuiltinPattern {
+    fn from(weekly: Weekly) -> Self {
+        BuiltinPattern::Weekly(weekly)
+    }
+}
+
+impl From<Explicit> for BuiltinPattern {
+    fn from(explicit: Explicit) -> Self {
+        BuiltinPattern::Explicit(explicit)
+    }
+}
+
+impl<L, R> From<Combined<L, R>> for BuiltinPattern
+where
+    L: Pattern + Into<BuiltinPattern>,
+    R: Pattern + Into<BuiltinPattern>,
+{
+    fn from(combined: Combined<L, R>) -> Self {
+        let (left, right) = combined.into_parts();
+        BuiltinPattern::combined(left, right)
+    }
+}
+
+impl<P> From<Union<P>> for BuiltinPattern
+where
+    P: Pattern + Into<BuiltinPattern>,
+{
+    fn from(union: Union<P>) -> Self {
+        BuiltinPattern::union(union.into_patterns())
+    }
+}
+
+impl<L, R> From<Intersect<L, R>> for BuiltinPattern
+where
+    L: Pattern + Into<BuiltinPattern>,
+    R: Pattern + Into<BuiltinPattern>,
+{
+    fn from(intersect: Intersect<L, R>) -> Self {
+        let budget = intersect.search_budget();
+        let (left, right) = intersect.into_parts();
+        let intersect = Intersect::new(left.into(), right.into()).budget(budget);
+        BuiltinPattern::Intersect(Box::new(intersect))
+    }
+}
+
+impl<P, X> From<Except<P, X>> for BuiltinPattern
+where
+    P: Pattern + Into<BuiltinPattern>,
+    X: Pattern + Into<BuiltinPattern>,
+{
+    fn from(except: Except<P, X>) -> Self {
+        let budget = except.search_budget();
+        let (pattern, exclusion) = except.into_parts();
+        let except = Except::new(pattern.into(), exclusion.into()).budget(budget);
+        BuiltinPattern::Except(Box::new(except))
+    }
+}
+
+impl<P> From<Shift<P>> for BuiltinPattern
+where
+    P: Pattern + Into<BuiltinPattern>,
+{
+    fn from(shift: Shift<P>) -> Self {
+        let offset = shift.offset();
+        BuiltinPattern::shift(shift.into_inner(), offset)
+    }
+}
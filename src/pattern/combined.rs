@@ -29,6 +29,11 @@ where
     pub fn new(left: L, right: R) -> Combined<L, R> {
         Combined { left, right }
     }
+
+    /// Returns the two combined recurrence patterns.
+    pub fn into_parts(self) -> (L, R) {
+        (self.left, self.right)
+    }
 }
 
 impl<L, R> Pattern for Combined<L, R>
@@ -0,0 +1,376 @@
+//! Formatting of [`Cron`] values as cron expressions.
+
+use super::{Cron, DayMatch};
+use crate::error::{Error, err};
+use crate::pattern::ranged::{Days, Milliseconds, RangedI8Set, Seconds, Years};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+impl fmt::Display for Cron {
+    /// Formats the `Cron` as a canonical cron expression.
+    ///
+    /// The output uses the standard five-field layout (`minute hour day-of-month month
+    /// day-of-week`) if the pattern only fires at second `0` and is not limited to specific years.
+    /// Otherwise a leading second field and, if needed, a trailing year field are added. See
+    /// [`Cron::parse`] for a description of the layouts.
+    ///
+    /// Field values are collapsed into ranges (`1-5`) and steps (`*/15`, `9-17/4`) where
+    /// possible. Weekdays are written as numbers from `1` (Monday) to `7` (Sunday). Days counted
+    /// from the end of the month are written as `L` (last day) or `L-n` (`n` days before the
+    /// last day), nearest weekdays as `dW` and nth weekdays as `d#n` or `dL` (last weekday `d` of
+    /// the month). These are supported by many, but not all cron implementations.
+    ///
+    /// Standard cron implementations combine restricted day-of-month and day-of-week fields using
+    /// [`DayMatch::Or`]. A `Cron` which restricts both fields using [`DayMatch::And`] has no
+    /// standard cron representation, so the output will describe a different schedule in that
+    /// case. The same applies to excluded days which affect days counted from the other end of the
+    /// month or nearest weekdays.
+    ///
+    /// Cron expressions have no millisecond, day-of-year or ISO week fields. Values configured via
+    /// [`.millisecond()`][Cron::millisecond], [`.day_of_year()`][Cron::day_of_year] and
+    /// [`.iso_week()`][Cron::iso_week] are omitted from the output.
+    ///
+    /// Cron expressions cannot describe fields without any value either. A pattern from which
+    /// all values of a field were excluded never matches, so it is written as `0 0 30 2 *`
+    /// (midnight on February 30th), which never matches as well.
+    ///
+    /// Use [`.to_cron_string()`][Cron::to_cron_string] to reject patterns which have no faithful
+    /// cron representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::cron;
+    ///
+    /// let pattern = cron().minute_step_by(0, 15).hours([9, 13, 17]).second(0);
+    /// assert_eq!(pattern.to_string(), "*/15 9-17/4 * * *");
+    ///
+    /// let pattern = cron().seconds([0, 30]).days([1, 2, 3, 5]).year(2030);
+    /// assert_eq!(pattern.to_string(), "*/30 * * 1-3,5 * * 2030");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.has_empty_field() {
+            return f.write_str("0 0 30 2 *");
+        }
+
+        let years = !self.years.is_full();
+
+        if years || self.seconds.range(Seconds::MIN..=Seconds::MAX).ne([0]) {
+            write_i8_set(f, self.seconds, true)?;
+            f.write_str(" ")?;
+        }
+
+        write_i8_set(f, self.minutes, true)?;
+        f.write_str(" ")?;
+        write_i8_set(f, self.hours, true)?;
+        f.write_str(" ")?;
+        self.write_days(f)?;
+        f.write_str(" ")?;
+        write_i8_set(f, self.months, true)?;
+        f.write_str(" ")?;
+        self.write_weekdays(f)?;
+
+        if years {
+            f.write_str(" ")?;
+            let values = self.years.range(Years::MIN..=Years::MAX);
+            write_field(f, values, false, Some(Years::MIN..=Years::MAX))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Cron {
+    /// Formats the `Cron` as a cron expression which describes the same schedule.
+    ///
+    /// This produces the same output as the [`Display`][fmt::Display] implementation, but fails
+    /// instead of returning an expression that [`Cron::parse`] would interpret differently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern has no faithful cron representation. This is the case if
+    /// both day fields are restricted and combined using [`DayMatch::And`], if it is limited to
+    /// negative years, specific milliseconds, days of the year or ISO weeks, if all values of a
+    /// field are excluded, or if excluded days affect days counted from the other end of the
+    /// month or nearest weekdays.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::Weekday;
+    /// use recurring::pattern::{Cron, DayMatch, cron};
+    ///
+    /// let pattern = cron().days([1, 15]).weekday(Weekday::Monday).hour(0).minute(0).second(0);
+    /// assert!(pattern.to_cron_string().is_err());
+    ///
+    /// let pattern = pattern.day_match(DayMatch::Or);
+    /// let expr = pattern.to_cron_string()?;
+    /// assert_eq!(expr, "0 0 1,15 * 1");
+    /// assert_eq!(Cron::parse(&expr)?, pattern);
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn to_cron_string(&self) -> Result<String, Error> {
+        if self.day_match == DayMatch::And && !self.days_is_full() && !self.weekdays_is_full() {
+            return Err(err!(
+                "cron expressions cannot require a day to match both day-of-month and day-of-week"
+            ));
+        }
+
+        if !self.years.is_full() && self.years.range(Years::MIN..=-1).next().is_some() {
+            return Err(err!("cron expressions cannot contain negative years"));
+        }
+
+        if self
+            .millisecond_range(Milliseconds::MIN..=Milliseconds::MAX)
+            .ne([0])
+        {
+            return Err(err!("cron expressions cannot contain milliseconds"));
+        }
+
+        if !self.days_of_year.is_full() || !self.iso_weeks.is_full() {
+            return Err(err!(
+                "cron expressions cannot contain days of the year or ISO weeks"
+            ));
+        }
+
+        if self.has_empty_field() {
+            return Err(err!("cron expressions cannot contain empty fields"));
+        }
+
+        // Excluded days are only part of the output if they don't affect days selected in any
+        // other way.
+        let days_excluded = self.days.has_exclusions()
+            && (!self.last_days.is_empty() || !self.nearest_weekdays.is_empty());
+        let last_days_excluded = self.last_days.has_exclusions()
+            && (self.last_days.is_empty()
+                || !self.days.is_empty()
+                || !self.nearest_weekdays.is_empty());
+
+        if days_excluded || last_days_excluded {
+            return Err(err!(
+                "cron expressions cannot describe excluded days which affect days counted from the \
+                 other end of the month or nearest weekdays"
+            ));
+        }
+
+        Ok(self.to_string())
+    }
+
+    /// Returns `true` if all values of any field were excluded.
+    fn has_empty_field(&self) -> bool {
+        let days = if self.last_days.is_empty() && self.nearest_weekdays.is_empty() {
+            is_cleared(self.days)
+        } else {
+            (self.days.is_empty() || is_cleared(self.days))
+                && (self.last_days.is_empty() || is_cleared(self.last_days))
+                && self.nearest_weekdays.is_empty()
+        };
+
+        let weekdays = if self.nth_weekdays.is_empty() {
+            is_cleared(self.weekdays)
+        } else {
+            (self.weekdays.is_empty() || is_cleared(self.weekdays))
+                && self
+                    .nth_weekdays
+                    .iter()
+                    .all(|(_, weekday)| self.weekdays.is_excluded(weekday))
+        };
+
+        days || weekdays
+            || is_cleared(self.seconds)
+            || is_cleared(self.minutes)
+            || is_cleared(self.hours)
+            || is_cleared(self.months)
+            || self.years.range(Years::MIN..=Years::MAX).next().is_none()
+    }
+
+    /// Writes the day-of-month field.
+    ///
+    /// Days counted from the end of the month are written as `L` (last day) and `L-n` (`n` days
+    /// before the last day). Nearest weekdays get a `W` suffix, e.g. `15W` or `LW`.
+    pub(super) fn write_days(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.last_days.is_empty() && self.nearest_weekdays.is_empty() {
+            return write_i8_set(f, self.days, true);
+        }
+
+        let mut separator = "";
+
+        if !self.days.is_empty() && !is_cleared(self.days) {
+            write_i8_set(f, self.days, true)?;
+            separator = ",";
+        }
+
+        let mut items: Vec<(i8, &str)> = Vec::new();
+
+        if !self.last_days.is_empty() {
+            let last_days = self.last_days.range(Days::MIN..=Days::MAX).rev();
+            items.extend(last_days.map(|from_end| (-from_end, "")));
+        }
+
+        items.extend(self.nearest_weekdays.iter().map(|day| (day, "W")));
+
+        for (day, suffix) in items {
+            f.write_str(separator)?;
+            separator = ",";
+
+            match day {
+                -1 => write!(f, "L{suffix}")?,
+                day if day < 0 => write!(f, "L-{}{suffix}", -day - 1)?,
+                day => write!(f, "{day}{suffix}")?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the day-of-week field.
+    ///
+    /// Nth weekdays are written as `d#n` (`n`th weekday `d` of the month) and `dL` (last weekday
+    /// `d` of the month).
+    pub(super) fn write_weekdays(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The weekday field starts at `0` (Sunday) in cron but our weekdays start at `1`
+        // (Monday), so `*/n` would describe different weekdays. Don't use it.
+        if self.nth_weekdays.is_empty() {
+            return write_i8_set(f, self.weekdays, false);
+        }
+
+        let mut separator = "";
+
+        if !self.weekdays.is_empty() && !is_cleared(self.weekdays) {
+            write_i8_set(f, self.weekdays, false)?;
+            separator = ",";
+        }
+
+        // Nth weekdays of excluded weekdays never match.
+        let nth_weekdays = self
+            .nth_weekdays
+            .iter()
+            .filter(|&(_, weekday)| !self.weekdays.is_excluded(weekday));
+
+        for (nth, weekday) in nth_weekdays {
+            f.write_str(separator)?;
+            separator = ",";
+
+            match nth {
+                -1 => write!(f, "{weekday}L")?,
+                nth => write!(f, "{weekday}#{nth}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if all values were removed from `set`.
+fn is_cleared<const MIN: i8, const MAX: i8>(set: RangedI8Set<MIN, MAX>) -> bool {
+    set.range(MIN..=MAX).next().is_none()
+}
+
+/// Formats a part of a cron expression using a write function like [`write_field`].
+pub(super) struct DisplayWith<F>(F);
+
+/// Returns a value which formats itself using `write`.
+pub(super) fn display_with<F>(write: F) -> DisplayWith<F>
+where
+    F: Fn(&mut fmt::Formatter) -> fmt::Result,
+{
+    DisplayWith(write)
+}
+
+impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Display for DisplayWith<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Writes the values of a `RangedI8Set`, see [`write_field`].
+pub(super) fn write_i8_set<const MIN: i8, const MAX: i8>(
+    f: &mut fmt::Formatter,
+    set: RangedI8Set<MIN, MAX>,
+    star: bool,
+) -> fmt::Result {
+    let values = set.range(MIN..=MAX);
+    write_field(f, values, set.is_full(), star.then_some(MIN..=MAX))
+}
+
+/// Writes the values of a cron field in their most compact form.
+///
+/// If `bounds` is `Some`, sets starting at the field minimum which step through the field up to
+/// its maximum are written as `*/n`.
+pub(super) fn write_field<T, I>(
+    f: &mut fmt::Formatter,
+    values: I,
+    full: bool,
+    bounds: Option<RangeInclusive<T>>,
+) -> fmt::Result
+where
+    T: Into<i16>,
+    I: Iterator<Item = T>,
+{
+    if full {
+        return f.write_str("*");
+    }
+
+    let values: Vec<i16> = values.map(Into::into).collect();
+
+    if let (Some(bounds), Some(step)) = (bounds, uniform_step(&values)) {
+        let (min, max) = bounds.into_inner();
+        let first = values[0];
+        let last = values[values.len() - 1];
+
+        if first == min.into() && last + step > max.into() {
+            return write!(f, "*/{step}");
+        }
+    }
+
+    let mut rest = values.as_slice();
+
+    while let Some(&first) = rest.first() {
+        if rest.len() < values.len() {
+            f.write_str(",")?;
+        }
+
+        let (len, step) = progression(rest);
+
+        if len >= 3 {
+            let last = rest[len - 1];
+            if step == 1 {
+                write!(f, "{first}-{last}")?;
+            } else {
+                write!(f, "{first}-{last}/{step}")?;
+            }
+            rest = &rest[len..];
+        } else {
+            write!(f, "{first}")?;
+            rest = &rest[1..];
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the step between all values if there are at least two values and all of them are
+/// evenly spaced.
+fn uniform_step(values: &[i16]) -> Option<i16> {
+    match progression(values) {
+        (len, step) if len >= 2 && len == values.len() => Some(step),
+        _ => None,
+    }
+}
+
+/// Returns the length and the step of the arithmetic progression at the start of `values`.
+fn progression(values: &[i16]) -> (usize, i16) {
+    let [first, second, ..] = *values else {
+        return (values.len(), 1);
+    };
+
+    let step = second - first;
+    let len = values
+        .windows(2)
+        .take_while(|pair| pair[1] - pair[0] == step)
+        .count();
+
+    (len + 1, step)
+}
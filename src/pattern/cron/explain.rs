@@ -0,0 +1,197 @@
+//! Checking whether a [`DateTime`] matches a [`Cron`] and explaining why it doesn't.
+
+use super::Cron;
+use super::display::{display_with, write_field, write_i8_set};
+use crate::pattern::ranged::{DaysOfYear, Milliseconds, RangedI8Set, Years};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use jiff::civil::DateTime;
+
+/// A field of a [`Cron`] pattern which rejected a [`DateTime`].
+///
+/// See [`Cron::explain`] for details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronMismatch {
+    field: &'static str,
+    value: String,
+    expected: String,
+}
+
+impl CronMismatch {
+    fn new(field: &'static str, value: impl fmt::Display, expected: impl fmt::Display) -> Self {
+        CronMismatch {
+            field,
+            value: value.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Returns the name of the field which rejected the `DateTime`, e.g. `month`.
+    pub fn field(&self) -> &str {
+        self.field
+    }
+
+    /// Returns the value of the `DateTime` for the field, e.g. `3` for March.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the values the field accepts in cron syntax, e.g. `1,6`.
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+impl fmt::Display for CronMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} is not in {}",
+            self.field, self.value, self.expected
+        )
+    }
+}
+
+impl Cron {
+    /// Returns `true` if the pattern ticks at `instant`.
+    ///
+    /// Use [`.explain()`][Cron::explain] to find out why an instant does not match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::pattern::cron;
+    ///
+    /// let pattern = cron().hour(10).minute(5).second(0);
+    ///
+    /// assert!(pattern.matches(date(2025, 3, 10).at(10, 5, 0, 0)));
+    /// assert!(!pattern.matches(date(2025, 3, 10).at(10, 6, 0, 0)));
+    /// ```
+    pub fn matches(&self, instant: DateTime) -> bool {
+        let (year, month, day) = (instant.year(), instant.month(), instant.day());
+
+        self.years.contains(year)
+            && self.months.contains(month)
+            && self.matches_day(year, month, day, self.matches_either_day())
+            && self.hours.contains(instant.hour())
+            && self.minutes.contains(instant.minute())
+            && self.seconds.contains(instant.second())
+            && instant.subsec_nanosecond() % 1_000_000 == 0
+            && self.matches_millisecond(instant.millisecond())
+    }
+
+    /// Returns the fields which reject `instant`.
+    ///
+    /// The returned list is empty if and only if the pattern [matches][Cron::matches] `instant`.
+    /// Otherwise it contains a [`CronMismatch`] for every field which rejects it. If days only
+    /// need to match either the day-of-month or the day-of-week field (see [`DayMatch::Or`]),
+    /// both fields are reported if neither matches.
+    ///
+    /// [`DayMatch::Or`]: super::DayMatch::Or
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date};
+    /// use recurring::pattern::cron;
+    ///
+    /// let pattern = cron()
+    ///     .months([1, 6])
+    ///     .weekdays([Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday])
+    ///     .hour(10).minute(5).second(0);
+    ///
+    /// let mismatches = pattern.explain(date(2025, 3, 9).at(10, 5, 0, 0));
+    /// let mismatches: Vec<_> = mismatches.iter().map(ToString::to_string).collect();
+    ///
+    /// assert_eq!(
+    ///     mismatches,
+    ///     ["month 3 is not in 1,6", "day of week 7 (Sunday) is not in 1-3"],
+    /// );
+    /// assert!(pattern.explain(date(2025, 1, 6).at(10, 5, 0, 0)).is_empty());
+    /// ```
+    pub fn explain(&self, instant: DateTime) -> Vec<CronMismatch> {
+        let mut mismatches = Vec::new();
+        let (year, month, day) = (instant.year(), instant.month(), instant.day());
+        let date = instant.date();
+
+        if !self.years.contains(year) {
+            let years = self.years.range(Years::MIN..=Years::MAX);
+            let bounds = Years::MIN..=Years::MAX;
+            let expected =
+                display_with(|f| write_field(f, years.clone(), false, Some(bounds.clone())));
+            mismatches.push(CronMismatch::new("year", year, expected));
+        }
+
+        push_i8_mismatch(&mut mismatches, "month", month, self.months);
+
+        let day_of_year = date.day_of_year();
+        if !self.days_of_year.contains(day_of_year) {
+            let days = self.days_of_year.range(DaysOfYear::MIN..=DaysOfYear::MAX);
+            let bounds = DaysOfYear::MIN..=DaysOfYear::MAX;
+            let expected =
+                display_with(|f| write_field(f, days.clone(), false, Some(bounds.clone())));
+            mismatches.push(CronMismatch::new("day of year", day_of_year, expected));
+        }
+
+        let week = date.iso_week_date().week();
+        if !self.iso_weeks.contains(week) {
+            let expected = display_with(|f| write_i8_set(f, self.iso_weeks, false));
+            mismatches.push(CronMismatch::new("ISO week", week, expected));
+        }
+
+        let day_of_month = self.matches_day_of_month(year, month, day);
+        let day_of_week = self.weekdays_is_full() || self.matches_day_of_week(date);
+
+        if !(self.matches_either_day() && (day_of_month || day_of_week)) {
+            if !day_of_month {
+                let expected = display_with(|f| self.write_days(f));
+                mismatches.push(CronMismatch::new("day of month", day, expected));
+            }
+
+            if !day_of_week {
+                let weekday = date.weekday();
+                let value = format!("{} ({weekday:?})", weekday.to_monday_one_offset());
+                let expected = display_with(|f| self.write_weekdays(f));
+                mismatches.push(CronMismatch::new("day of week", value, expected));
+            }
+        }
+
+        push_i8_mismatch(&mut mismatches, "hour", instant.hour(), self.hours);
+        push_i8_mismatch(&mut mismatches, "minute", instant.minute(), self.minutes);
+        push_i8_mismatch(&mut mismatches, "second", instant.second(), self.seconds);
+
+        let millisecond = instant.millisecond();
+        let remainder = instant.subsec_nanosecond() % 1_000_000;
+        if remainder != 0 || !self.matches_millisecond(millisecond) {
+            let value = if remainder == 0 {
+                millisecond.to_string()
+            } else {
+                format!("{millisecond}.{remainder:06}")
+            };
+
+            let milliseconds = self.millisecond_range(Milliseconds::MIN..=Milliseconds::MAX);
+            let bounds = Milliseconds::MIN..=Milliseconds::MAX;
+            let expected =
+                display_with(|f| write_field(f, milliseconds.clone(), false, Some(bounds.clone())));
+            mismatches.push(CronMismatch::new("millisecond", value, expected));
+        }
+
+        mismatches
+    }
+}
+
+/// Adds a mismatch to `mismatches` if `set` does not contain `value`.
+fn push_i8_mismatch<const MIN: i8, const MAX: i8>(
+    mismatches: &mut Vec<CronMismatch>,
+    field: &'static str,
+    value: i8,
+    set: RangedI8Set<MIN, MAX>,
+) {
+    if !set.contains(value) {
+        let expected = display_with(|f| write_i8_set(f, set, true));
+        mismatches.push(CronMismatch::new(field, value, expected));
+    }
+}
@@ -0,0 +1,249 @@
+//! Hashed cron fields for spreading the load of many similar schedules.
+
+use super::{Cron, check_step_range};
+use crate::error::Error;
+use crate::pattern::ranged::{Days, Hours, Minutes, Months, Seconds, Weekdays};
+use core::ops::RangeInclusive;
+
+/// A field of a [`Cron`] pattern which supports hashed values.
+///
+/// See [`Cron::hashed`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronField {
+    /// The second field (`0` to `59`).
+    Second,
+    /// The minute field (`0` to `59`).
+    Minute,
+    /// The hour field (`0` to `23`).
+    Hour,
+    /// The day-of-month field (`1` to `31`).
+    Day,
+    /// The month field (`1` to `12`).
+    Month,
+    /// The day-of-week field (`1` for Monday to `7` for Sunday).
+    Weekday,
+}
+
+impl CronField {
+    /// Returns the range of valid values for the field.
+    pub(super) fn bounds(self) -> RangeInclusive<i8> {
+        match self {
+            CronField::Second => Seconds::MIN..=Seconds::MAX,
+            CronField::Minute => Minutes::MIN..=Minutes::MAX,
+            CronField::Hour => Hours::MIN..=Hours::MAX,
+            CronField::Day => Days::MIN..=Days::MAX,
+            CronField::Month => Months::MIN..=Months::MAX,
+            CronField::Weekday => Weekdays::MIN..=Weekdays::MAX,
+        }
+    }
+
+    /// Returns the range of values for hashed values without an explicit range.
+    ///
+    /// Like in Jenkins, the day-of-month field is limited to `1` to `28`, which exist in every
+    /// month.
+    pub(super) fn hashed_bounds(self) -> RangeInclusive<i8> {
+        match self {
+            CronField::Day => Days::MIN..=28,
+            field => field.bounds(),
+        }
+    }
+
+    /// Returns the hash for this field which is derived from the seed hash.
+    fn hash(self, seed: u64) -> u64 {
+        // The SplitMix64 finalizer, which spreads similar inputs across the whole output range.
+        let mut hash = seed ^ (self as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^ (hash >> 31)
+    }
+
+    /// Returns the hashed values from `start` to `end` (inclusive).
+    ///
+    /// Without a `step`, this produces a single value. Otherwise the values start at a hashed
+    /// offset smaller than `step` and are `step` apart.
+    pub(super) fn hashed_values(
+        self,
+        seed: u64,
+        start: i16,
+        end: i16,
+        step: Option<usize>,
+    ) -> impl Iterator<Item = i16> {
+        let len = end.abs_diff(start) + 1;
+        let hash = self.hash(seed);
+
+        let (offset, step) = match step {
+            Some(step) => (hash % u64::from(len).min(step as u64), step),
+            None => (hash % u64::from(len), usize::from(len)),
+        };
+
+        let offset = i16::try_from(offset).unwrap_or_default();
+        (start + offset..=end).step_by(step)
+    }
+}
+
+impl Cron {
+    /// Set the seed for hashed fields.
+    ///
+    /// The seed is usually some stable identifier like the name of a job. Patterns with
+    /// different seeds resolve hashed fields to different values, which spreads the load of many
+    /// patterns which would otherwise all tick at the same time. The seed is kept on the `Cron`,
+    /// so hashed fields always resolve to the same values for the same seed.
+    ///
+    /// The seed only affects hashed fields configured after calling this method. Without a seed,
+    /// all patterns resolve hashed fields to the same values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::{CronField, cron};
+    ///
+    /// let a = cron().seed("job-1").hashed(CronField::Minute).second(0);
+    /// let b = cron().seed("job-1").hashed(CronField::Minute).second(0);
+    ///
+    /// assert_eq!(a.to_string(), b.to_string());
+    /// ```
+    #[must_use]
+    pub fn seed(mut self, seed: &str) -> Cron {
+        // FNV-1a, which is stable across platforms and releases unlike `core::hash::Hash`.
+        self.seed = seed.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self
+    }
+
+    /// Limit a field to a single value which is derived from the seed.
+    ///
+    /// This is equivalent to `H` in Jenkins cron expressions. The value is chosen from all valid
+    /// values of the field and stays the same for the same seed. See [`.seed()`][Cron::seed].
+    /// Like in Jenkins, days of the month are chosen from `1` to `28` so that the pattern ticks in
+    /// every month.
+    ///
+    /// Please note that the resolved values differ from the values Jenkins would choose for the
+    /// same seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::{CronField, cron};
+    ///
+    /// // Once per hour at a minute which depends on the job ID.
+    /// let pattern = cron().seed("job-42").hashed(CronField::Minute).second(0);
+    /// ```
+    #[must_use]
+    pub fn hashed(self, field: CronField) -> Cron {
+        self.hashed_in(field, field.hashed_bounds())
+    }
+
+    /// Limit a field to a single value within `range` which is derived from the seed.
+    ///
+    /// This is equivalent to `H(a-b)` in Jenkins cron expressions. See
+    /// [`.hashed()`][Cron::hashed] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_hashed_in`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the range is empty or when its bounds are outside of the valid values of
+    /// the field.
+    #[must_use]
+    pub fn hashed_in(self, field: CronField, range: RangeInclusive<i8>) -> Cron {
+        self.try_hashed_in(field, range)
+            .expect("value for hashed range is out of bounds")
+    }
+
+    /// Limit a field to every `step`'s value within `range` starting at an offset which is
+    /// derived from the seed.
+    ///
+    /// This is equivalent to `H(a-b)/n` in Jenkins cron expressions. The offset from the start of
+    /// the range is smaller than `step`. See [`.hashed()`][Cron::hashed] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_hashed_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the range is empty, when its bounds are outside of the valid values of
+    /// the field or if the given step is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::{CronField, cron};
+    ///
+    /// // Every 10 minutes during the first half of each hour, e.g. at 07, 17 and 27.
+    /// let pattern = cron()
+    ///     .seed("job-42")
+    ///     .hashed_step_by(CronField::Minute, 0..=29, 10)
+    ///     .second(0);
+    /// ```
+    #[must_use]
+    pub fn hashed_step_by(self, field: CronField, range: RangeInclusive<i8>, step: usize) -> Cron {
+        self.try_hashed_step_by(field, range, step)
+            .expect("value for hashed step is out of bounds")
+    }
+
+    /// Limit a field to a single value within `range` which is derived from the seed.
+    ///
+    /// See [`.hashed_in()`][Cron::hashed_in] for details.
+    ///
+    /// The panicking version of this method is [`Cron::hashed_in`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the range is empty or when its bounds are outside of the valid
+    /// values of the field.
+    pub fn try_hashed_in(self, field: CronField, range: RangeInclusive<i8>) -> Result<Cron, Error> {
+        self.try_hashed(field, range, None)
+    }
+
+    /// Limit a field to every `step`'s value within `range` starting at an offset which is
+    /// derived from the seed.
+    ///
+    /// See [`.hashed_step_by()`][Cron::hashed_step_by] for details.
+    ///
+    /// The panicking version of this method is [`Cron::hashed_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the range is empty, when its bounds are outside of the valid
+    /// values of the field or if the given step is `0`.
+    pub fn try_hashed_step_by(
+        self,
+        field: CronField,
+        range: RangeInclusive<i8>,
+        step: usize,
+    ) -> Result<Cron, Error> {
+        self.try_hashed(field, range, Some(step))
+    }
+
+    fn try_hashed(
+        self,
+        field: CronField,
+        range: RangeInclusive<i8>,
+        step: Option<usize>,
+    ) -> Result<Cron, Error> {
+        let (min, max) = field.bounds().into_inner();
+        check_step_range(&range, step.unwrap_or(1), min, max)?;
+
+        let (start, end) = range.into_inner();
+        field
+            .hashed_values(self.seed, start.into(), end.into(), step)
+            .try_fold(self, |cron, value| cron.try_field(field, value))
+    }
+
+    /// Adds a value to a field.
+    fn try_field(mut self, field: CronField, value: i16) -> Result<Cron, Error> {
+        let value = i8::try_from(value).map_err(|_| Error::range(value, i8::MIN, i8::MAX))?;
+
+        match field {
+            CronField::Second => self.try_second(value),
+            CronField::Minute => self.try_minute(value),
+            CronField::Hour => self.try_hour(value),
+            CronField::Day => self.try_day(value),
+            CronField::Month => self.try_month(value),
+            CronField::Weekday => {
+                self.weekdays.try_insert(value)?;
+                Ok(self)
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Predefined cron macros like `@daily`.
+
+use super::Cron;
+use crate::error::{Error, err};
+use core::fmt;
+use core::str::FromStr;
+use jiff::civil::Weekday;
+
+/// A predefined cron schedule like `@daily`.
+///
+/// Many cron implementations support these macros as shorthands for common cron expressions.
+/// All of them fire at second `0`.
+///
+/// A `CronMacro` can be converted into the equivalent [`Cron`] via `From`. It can also be parsed
+/// from its name via `FromStr` and is formatted as its name by `Display`.
+///
+/// # Example
+///
+/// ```
+/// use recurring::pattern::{Cron, CronMacro};
+///
+/// let macro_: CronMacro = "@daily".parse()?;
+/// assert_eq!(macro_, CronMacro::Daily);
+/// assert_eq!(macro_.to_string(), "@daily");
+///
+/// let pattern = Cron::from(macro_);
+/// assert_eq!(pattern.to_string(), "0 0 * * *");
+/// assert_eq!(pattern.as_macro(), Some(CronMacro::Daily));
+/// # Ok::<(), Box<dyn core::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronMacro {
+    /// Once a year at midnight on January 1st (`@yearly` or `@annually`), equivalent to
+    /// `0 0 1 1 *`.
+    Yearly,
+    /// Once a month at midnight on the first day of the month (`@monthly`), equivalent to
+    /// `0 0 1 * *`.
+    Monthly,
+    /// Once a week at midnight on Sunday (`@weekly`), equivalent to `0 0 * * 7`.
+    Weekly,
+    /// Once a day at midnight (`@daily` or `@midnight`), equivalent to `0 0 * * *`.
+    Daily,
+    /// Once an hour at the beginning of the hour (`@hourly`), equivalent to `0 * * * *`.
+    Hourly,
+}
+
+impl CronMacro {
+    /// All cron macros.
+    const ALL: [CronMacro; 5] = [
+        CronMacro::Yearly,
+        CronMacro::Monthly,
+        CronMacro::Weekly,
+        CronMacro::Daily,
+        CronMacro::Hourly,
+    ];
+
+    /// Returns the name of the macro including the leading `@`, e.g. `@daily`.
+    pub fn name(self) -> &'static str {
+        match self {
+            CronMacro::Yearly => "@yearly",
+            CronMacro::Monthly => "@monthly",
+            CronMacro::Weekly => "@weekly",
+            CronMacro::Daily => "@daily",
+            CronMacro::Hourly => "@hourly",
+        }
+    }
+}
+
+impl fmt::Display for CronMacro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for CronMacro {
+    type Err = Error;
+
+    /// Parses a cron macro from its name.
+    ///
+    /// Names are matched case-insensitively. Besides the names returned by
+    /// [`CronMacro::name`], the aliases `@annually` and `@midnight` are supported.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("@annually") {
+            return Ok(CronMacro::Yearly);
+        }
+
+        if s.eq_ignore_ascii_case("@midnight") {
+            return Ok(CronMacro::Daily);
+        }
+
+        CronMacro::ALL
+            .into_iter()
+            .find(|macro_| macro_.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| err!("unknown cron macro \"{s}\""))
+    }
+}
+
+impl From<CronMacro> for Cron {
+    fn from(macro_: CronMacro) -> Cron {
+        match macro_ {
+            CronMacro::Yearly => Cron::yearly(),
+            CronMacro::Monthly => Cron::monthly(),
+            CronMacro::Weekly => Cron::weekly(),
+            CronMacro::Daily => Cron::daily(),
+            CronMacro::Hourly => Cron::hourly(),
+        }
+    }
+}
+
+impl Cron {
+    /// Create a new `Cron` that ticks once a year at midnight on January 1st.
+    ///
+    /// This is equivalent to the cron macro `@yearly`.
+    pub fn yearly() -> Cron {
+        Cron::monthly().month(1)
+    }
+
+    /// Create a new `Cron` that ticks once a month at midnight on the first day of the month.
+    ///
+    /// This is equivalent to the cron macro `@monthly`.
+    pub fn monthly() -> Cron {
+        Cron::daily().day(1)
+    }
+
+    /// Create a new `Cron` that ticks once a week at midnight on Sunday.
+    ///
+    /// This is equivalent to the cron macro `@weekly`.
+    pub fn weekly() -> Cron {
+        Cron::daily().weekday(Weekday::Sunday)
+    }
+
+    /// Create a new `Cron` that ticks once a day at midnight.
+    ///
+    /// This is equivalent to the cron macro `@daily`.
+    pub fn daily() -> Cron {
+        Cron::hourly().hour(0)
+    }
+
+    /// Create a new `Cron` that ticks once an hour at the beginning of the hour.
+    ///
+    /// This is equivalent to the cron macro `@hourly`.
+    pub fn hourly() -> Cron {
+        Cron::new().minute(0).second(0)
+    }
+
+    /// Returns the cron macro which describes exactly the same schedule as this `Cron`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::{Cron, CronMacro, cron};
+    ///
+    /// assert_eq!(Cron::parse("0 0 1 * *")?.as_macro(), Some(CronMacro::Monthly));
+    /// assert_eq!(cron().hour(0).minute(0).second(0).as_macro(), Some(CronMacro::Daily));
+    /// assert_eq!(cron().hour(12).minute(0).second(0).as_macro(), None);
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn as_macro(&self) -> Option<CronMacro> {
+        CronMacro::ALL
+            .into_iter()
+            .find(|&macro_| Cron::from(macro_) == *self)
+    }
+}
@@ -0,0 +1,2810 @@
+mod display;
+mod explain;
+mod hashed;
+mod macros;
+mod parse;
+
+pub use explain::CronMismatch;
+pub use hashed::CronField;
+pub use macros::CronMacro;
+
+use super::ranged::{
+    Days, DaysOfYear, Hours, IsoWeeks, Milliseconds, Minutes, Months, RangeIter, Seconds, Weekdays,
+    Years,
+};
+use crate::error::{Error, err};
+use crate::{DateTimeRange, Pattern, private};
+use core::fmt;
+use core::ops::{RangeBounds, RangeInclusive};
+use jiff::ToSpan;
+use jiff::civil::{Date, DateTime, Weekday};
+
+/// A cron-like recurrence pattern.
+///
+/// You can imagine this as some more granular form of the the cron pattern `* * * * *` (at
+/// every minute). `Cron` also includes a 6th component to facilitate second precision.
+///
+/// The default value of a `Cron` produces an event at every second, which would be equivalent to
+/// the second-enhanced cron pattern of `* * * * * *`. Events fire at the start of the second
+/// unless the pattern is limited to specific milliseconds via
+/// [`.millisecond()`][Cron::millisecond].
+///
+/// After constructed, this type has various builder methods like [`.second()`][Cron::second]
+/// and [`.hours()`][Cron::hours] to configure the details of the cron pattern. Alternatively, a
+/// `Cron` can be parsed from a cron expression via [`Cron::parse`] or its `FromStr`
+/// implementation.
+///
+/// # Example: once per day at a certain time
+///
+/// ```
+/// # use recurring::pattern::Cron;
+/// // Every day at 12:30.
+/// let pattern = Cron::new().hour(12).minute(30);
+/// ```
+///
+/// # Example: at multiple occasion thoughout the day
+///
+/// ```
+/// # use recurring::pattern::Cron;
+/// // Every day at 12:30, 13:30 and 14:30.
+/// let pattern = Cron::new().hours(12..15).minute(30);
+/// ```
+///
+/// # Example: multiple non-consecutive time units
+///
+/// ```
+/// # use recurring::pattern::Cron;
+/// // Every day at 10:30 and 20:30.
+/// let pattern = Cron::new().hour(10).hour(20).minute(30);
+/// // Or
+/// let pattern = Cron::new().hours([10, 20]).minute(30);
+/// ```
+///
+/// # Example: pitfalls
+///
+/// Because `Cron` behaves pretty much like a cron pattern, the following is expected behaviour
+/// but might not be what you want.
+///
+/// ```
+/// # use recurring::pattern::Cron;
+/// // This ticks at 10:15 and 20:30. But it also ticks at 10:30 and 20:15.
+/// let pattern = Cron::new()
+///     .hour(10).minute(15)
+///     .hour(20).minute(30);
+///
+/// // And so does this.
+/// let pattern = Cron::new().hours([10, 20]).minutes([15, 30]);
+///
+/// // Let's break it down:
+/// let pattern = Cron::new()
+///     .hour(10)    // Tick at hour 10.
+///     .minute(15)  // Tick at minute 15.
+///     .hour(20)    // Tick at hour 20 (we already tick at 10 too).
+///     .minute(30); // Tick at minute 30 (we already tick at 15 too).
+///
+/// // This ticks every day at:
+/// //
+/// // - On hour 10 at minute 15 and 30
+/// // - On hour 20 at minute 15 and 30
+/// ```
+///
+/// # Example: time unit ranges
+///
+/// ```
+/// // This ticks at every hour from 10 to 19 at every minute from 15 to 29 in european summer
+/// // months:
+/// # use recurring::pattern::Cron;
+/// let pattern = Cron::new().months(6..=9).hours(10..=20).minutes(15..30);
+/// ```
+///
+/// # Example: parsing a cron expression
+///
+/// ```
+/// # use recurring::pattern::Cron;
+/// // Every 15 minutes between 08:00 and 17:59 on weekdays.
+/// let pattern: Cron = "*/15 8-17 * * MON-FRI".parse()?;
+/// # Ok::<(), Box<dyn core::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Cron {
+    years: Years,
+    months: Months,
+    weekdays: Weekdays,
+    days: Days,
+    /// Days counted backwards from the end of the month, where `1` is the last day.
+    last_days: Days,
+    nearest_weekdays: NearestWeekdays,
+    nth_weekdays: NthWeekdays,
+    days_of_year: DaysOfYear,
+    iso_weeks: IsoWeeks,
+    hours: Hours,
+    minutes: Minutes,
+    seconds: Seconds,
+    /// Milliseconds, or `None` if the pattern only ticks at the start of each second.
+    milliseconds: Option<Milliseconds>,
+    day_match: DayMatch,
+    /// Hash of the seed for hashed fields.
+    seed: u64,
+}
+
+impl Cron {
+    /// Create a new `Cron` that ticks every second.
+    pub fn new() -> Cron {
+        Cron::default()
+    }
+
+    /// Set how the day-of-month and day-of-week fields are combined.
+    ///
+    /// The default is [`DayMatch::And`], which requires a day to match both fields. See
+    /// [`DayMatch`] for the available options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date};
+    /// use recurring::{Event, ToSeries, pattern::{DayMatch, cron}};
+    ///
+    /// // At midnight on the 1st and 15th of the month and on every Monday.
+    /// let pattern = cron()
+    ///     .days([1, 15])
+    ///     .weekday(Weekday::Monday)
+    ///     .hour(0).minute(0).second(0)
+    ///     .day_match(DayMatch::Or);
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(0, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 6).at(0, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 13).at(0, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 15).at(0, 0, 0, 0))));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn day_match(mut self, day_match: DayMatch) -> Cron {
+        self.day_match = day_match;
+        self
+    }
+
+    /// Returns `true` if the pattern matches at least one point in time.
+    ///
+    /// Some combinations of fields can never match, for example the 30th of February or the
+    /// 31st of a month with only 30 days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::cron;
+    ///
+    /// assert!(cron().month(2).day(29).is_satisfiable());
+    /// assert!(!cron().month(2).day(30).is_satisfiable());
+    /// assert!(!cron().months([4, 6]).day(31).is_satisfiable());
+    /// assert!(!cron().years([2025, 2026, 2027]).month(2).day(29).is_satisfiable());
+    /// ```
+    pub fn is_satisfiable(&self) -> bool {
+        self.satisfiable_month_shapes().is_some()
+    }
+
+    /// Checks that the pattern matches at least one point in time.
+    ///
+    /// This can be used at the end of a chain of builder methods to reject patterns which never
+    /// match. See [`.is_satisfiable()`][Cron::is_satisfiable] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern can never match.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::cron;
+    ///
+    /// assert!(cron().month(2).day(28).validate().is_ok());
+    /// assert!(cron().month(2).day(30).validate().is_err());
+    /// ```
+    pub fn validate(self) -> Result<Cron, Error> {
+        if self.is_satisfiable() {
+            Ok(self)
+        } else {
+            Err(err!("cron pattern can never match"))
+        }
+    }
+}
+
+/// Two `Cron` values are compared field by field after normalisation: each field is compared by
+/// the values it matches, regardless of how they were inserted or excluded. Patterns which never
+/// tick, like `cron().month(2).day(30)` and `cron().month(2).day(31)`, are therefore not equal.
+///
+/// The [`DayMatch`] mode is only compared if both day fields are restricted, since it has no
+/// effect otherwise. The seed is not compared as it only affects hashed values while they are
+/// resolved.
+impl PartialEq for Cron {
+    fn eq(&self, other: &Cron) -> bool {
+        // Whether a set is restricted matters for the day fields if it is combined with other
+        // sets, as an unrestricted set does not contribute any days in that case.
+        fn same_days(a: Days, b: Days, combined: bool) -> bool {
+            a == b && (!combined || a.is_empty() == b.is_empty())
+        }
+
+        let day_match = |cron: &Cron| {
+            (!cron.days_is_full() && !cron.weekdays_is_full()).then_some(cron.day_match)
+        };
+
+        let combined_days =
+            |cron: &Cron| !cron.last_days.is_empty() || !cron.nearest_weekdays.is_empty();
+
+        self.years == other.years
+            && self.months == other.months
+            && self.weekdays == other.weekdays
+            && (self.nth_weekdays.is_empty()
+                || self.weekdays.is_empty() == other.weekdays.is_empty())
+            && self.nth_weekdays == other.nth_weekdays
+            && same_days(self.days, other.days, combined_days(self))
+            && same_days(self.last_days, other.last_days, true)
+            && self.nearest_weekdays == other.nearest_weekdays
+            && self.days_of_year == other.days_of_year
+            && self.iso_weeks == other.iso_weeks
+            && self.hours == other.hours
+            && self.minutes == other.minutes
+            && self.seconds == other.seconds
+            && self
+                .millisecond_range(Milliseconds::MIN..=Milliseconds::MAX)
+                .eq(other.millisecond_range(Milliseconds::MIN..=Milliseconds::MAX))
+            && day_match(self) == day_match(other)
+    }
+}
+
+impl Eq for Cron {}
+
+/// Controls how the day-of-month and day-of-week fields of a [`Cron`] are combined.
+///
+/// The two modes only behave differently if both fields are restricted to specific values. If at
+/// least one of them matches every value, a day has to match the other one in both modes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DayMatch {
+    /// A day has to match both the day-of-month and the day-of-week field.
+    ///
+    /// For example, `cron().day(13).weekday(Weekday::Friday)` only ticks on Friday the 13th.
+    #[default]
+    And,
+    /// A day has to match either the day-of-month or the day-of-week field.
+    ///
+    /// This is how classic Unix (Vixie) cron interprets expressions like `0 0 1,15 * MON`, which
+    /// fires on the 1st and 15th of each month as well as on every Monday.
+    Or,
+}
+
+// Panicking builder methods.
+impl Cron {
+    /// Limit the pattern to a specific year.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different
+    /// years. Alternatively, you can use [`.years()`][Cron::years] to feed years from an
+    /// iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_year`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the year is too small or too big. The minimum value is `-9999`. The
+    /// maximum value is `9999`.
+    #[must_use]
+    pub fn year(self, year: i16) -> Cron {
+        self.try_year(year)
+            .expect("value for year is out of bounds")
+    }
+
+    /// Limit the years in the pattern to every `step`'s year from `start` onwards.
+    ///
+    /// The fallible version of this method is [`Cron::try_year_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `-9999`. The maximum start value is `9999`.
+    #[must_use]
+    pub fn year_step_by(self, start: i16, step: usize) -> Cron {
+        self.try_year_step_by(start, step)
+            .expect("value for year step is out of bounds")
+    }
+
+    /// Limit the years in the pattern to specific values from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_years`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the year values produced by the iterator is year is too small
+    /// or too big. The minimum value is `-9999`. The maximum value is `9999`.
+    #[must_use]
+    pub fn years<I: IntoIterator<Item = i16>>(self, years: I) -> Cron {
+        self.try_years(years)
+            .expect("value for years is out of bounds")
+    }
+
+    /// Limit the years in the pattern to every `step`'s year within `range`.
+    ///
+    /// The fallible version of this method is [`Cron::try_years_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `-9999`. The maximum value is `9999`.
+    #[must_use]
+    pub fn years_step_by(self, range: RangeInclusive<i16>, step: usize) -> Cron {
+        self.try_years_step_by(range, step)
+            .expect("value for years step is out of bounds")
+    }
+
+    /// Exclude a specific year from the pattern.
+    ///
+    /// The year is excluded regardless of whether the pattern is limited to specific years
+    /// before or after calling this method. A pattern whose years are all excluded never matches.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_year`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the year is too small or too big. The minimum value is `-9999`. The
+    /// maximum value is `9999`.
+    #[must_use]
+    pub fn exclude_year(self, year: i16) -> Cron {
+        self.try_exclude_year(year)
+            .expect("value for year is out of bounds")
+    }
+
+    /// Exclude specific years produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_year()`][Cron::exclude_year] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_years`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the year values produced by the iterator is too small or too
+    /// big. The minimum value is `-9999`. The maximum value is `9999`.
+    #[must_use]
+    pub fn exclude_years<I: IntoIterator<Item = i16>>(self, years: I) -> Cron {
+        self.try_exclude_years(years)
+            .expect("value for years is out of bounds")
+    }
+
+    /// Limit the pattern to a specific month.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different
+    /// months. Alternatively, you can use [`.months()`][Cron::months] to feed months from an
+    /// iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_month`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the month is too small or too big. The minimum value is `1`. The maximum
+    /// value is `12`.
+    #[must_use]
+    pub fn month(self, month: i8) -> Cron {
+        self.try_month(month)
+            .expect("value for month is out of bounds")
+    }
+
+    /// Limit the months in the pattern to every `step`'s month from `start` onwards.
+    ///
+    /// The fallible version of this method is [`Cron::try_month_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `1`. The maximum start value is `12`.
+    #[must_use]
+    pub fn month_step_by(self, start: i8, step: usize) -> Cron {
+        self.try_month_step_by(start, step)
+            .expect("value for month step is out of bounds")
+    }
+
+    /// Limit the months in the pattern to specific values from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_months`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the month values produced by the iterator is month is too small
+    /// or too big. The minimum value is `1`. The maximum value is `12`.
+    #[must_use]
+    pub fn months<I: IntoIterator<Item = i8>>(self, months: I) -> Cron {
+        self.try_months(months)
+            .expect("value for months is out of bounds")
+    }
+
+    /// Limit the months in the pattern to every `step`'s month within `range`.
+    ///
+    /// The fallible version of this method is [`Cron::try_months_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `1`. The maximum value is `12`.
+    #[must_use]
+    pub fn months_step_by(self, range: RangeInclusive<i8>, step: usize) -> Cron {
+        self.try_months_step_by(range, step)
+            .expect("value for months step is out of bounds")
+    }
+
+    /// Exclude a specific month from the pattern.
+    ///
+    /// The month is excluded regardless of whether the pattern is limited to specific months
+    /// before or after calling this method. A pattern whose months are all excluded never matches.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_month`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the month is too small or too big. The minimum value is `1`. The
+    /// maximum value is `12`.
+    #[must_use]
+    pub fn exclude_month(self, month: i8) -> Cron {
+        self.try_exclude_month(month)
+            .expect("value for month is out of bounds")
+    }
+
+    /// Exclude specific months produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_month()`][Cron::exclude_month] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_months`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the month values produced by the iterator is too small or too
+    /// big. The minimum value is `1`. The maximum value is `12`.
+    #[must_use]
+    pub fn exclude_months<I: IntoIterator<Item = i8>>(self, months: I) -> Cron {
+        self.try_exclude_months(months)
+            .expect("value for months is out of bounds")
+    }
+
+    /// Limit the pattern to a specific weekday.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different
+    /// weekdays. Alternatively, you can use [`.weekdays()`][Cron::weekdays] to feed weekdays
+    /// from an iterator.
+    #[must_use]
+    pub fn weekday(self, weekday: Weekday) -> Cron {
+        self.weekday_i8(weekday.to_monday_one_offset())
+    }
+
+    #[inline]
+    fn weekday_i8(mut self, weekday: i8) -> Cron {
+        self.weekdays
+            .try_insert(weekday)
+            .expect("weekday is out of bounds, please file a bug");
+        self
+    }
+
+    /// Limit the weekdays in the pattern to every `step`'s weekday from `start` onwards.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    #[must_use]
+    pub fn weekday_step_by(self, start: Weekday, step: usize) -> Cron {
+        (start.to_monday_one_offset()..=Weekdays::MAX)
+            .step_by(step)
+            .fold(self, Cron::weekday_i8)
+    }
+
+    /// Limit the weekdays in the pattern to specific values from an iterator.
+    #[must_use]
+    pub fn weekdays<I: IntoIterator<Item = Weekday>>(self, weekdays: I) -> Cron {
+        weekdays.into_iter().fold(self, Cron::weekday)
+    }
+
+    /// Limit the weekdays in the pattern to every `step`'s weekday within `range`.
+    ///
+    /// The range starts at Monday and ends at Sunday, so `Weekday::Friday..=Weekday::Monday` is
+    /// empty.
+    ///
+    /// The fallible version of this method is [`Cron::try_weekdays_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0` or if the range is empty.
+    #[must_use]
+    pub fn weekdays_step_by(self, range: RangeInclusive<Weekday>, step: usize) -> Cron {
+        self.try_weekdays_step_by(range, step)
+            .expect("value for weekdays step is out of bounds")
+    }
+
+    /// Exclude a specific weekday from the pattern.
+    ///
+    /// The weekday is excluded regardless of whether the pattern is limited to specific weekdays
+    /// before or after calling this method. This also applies to nth weekdays configured via
+    /// [`.nth_weekday()`][Cron::nth_weekday]. A pattern whose weekdays are all excluded never
+    /// matches.
+    #[must_use]
+    pub fn exclude_weekday(self, weekday: Weekday) -> Cron {
+        self.exclude_weekday_i8(weekday.to_monday_one_offset())
+    }
+
+    #[inline]
+    fn exclude_weekday_i8(mut self, weekday: i8) -> Cron {
+        self.weekdays
+            .try_exclude(weekday)
+            .expect("weekday is out of bounds, please file a bug");
+        self
+    }
+
+    /// Exclude specific weekdays produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_weekday()`][Cron::exclude_weekday] for details.
+    #[must_use]
+    pub fn exclude_weekdays<I: IntoIterator<Item = Weekday>>(self, weekdays: I) -> Cron {
+        weekdays.into_iter().fold(self, Cron::exclude_weekday)
+    }
+
+    /// Limit the pattern to the `nth` occurrence of a weekday within the month.
+    ///
+    /// Positive values of `nth` count from the start of the month, i.e. `1` is the first
+    /// occurrence of `weekday` in the month. Negative values count backwards from the end of the
+    /// month, i.e. `-1` is the last occurrence of `weekday` in the month.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different nth
+    /// weekdays. The pattern matches if a day matches any of the configured nth weekdays or any of
+    /// the weekdays configured via [`.weekday()`][Cron::weekday].
+    ///
+    /// The fallible version of this method is [`Cron::try_nth_weekday`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when `nth` is zero, too small or too big. The minimum value is `-5`. The
+    /// maximum value is `5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date};
+    /// use recurring::{Event, ToSeries, pattern::cron};
+    ///
+    /// // At 10:00 on the second Tuesday of each month.
+    /// let pattern = cron().nth_weekday(2, Weekday::Tuesday).hour(10).minute(0).second(0);
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 14).at(10, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 2, 11).at(10, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 3, 11).at(10, 0, 0, 0))));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn nth_weekday(self, nth: i8, weekday: Weekday) -> Cron {
+        self.try_nth_weekday(nth, weekday)
+            .expect("value for nth weekday is out of bounds")
+    }
+
+    /// Limit the pattern to the last occurrence of a weekday within the month.
+    ///
+    /// This is equivalent to calling [`.nth_weekday(-1, weekday)`][Cron::nth_weekday].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date};
+    /// use recurring::{Event, ToSeries, pattern::cron};
+    ///
+    /// // At 17:00 on the last Friday of each month.
+    /// let pattern = cron().last_weekday(Weekday::Friday).hour(17).minute(0).second(0);
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 31).at(17, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 2, 28).at(17, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 3, 28).at(17, 0, 0, 0))));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn last_weekday(self, weekday: Weekday) -> Cron {
+        self.nth_weekday(-1, weekday)
+    }
+
+    /// Limit the pattern to a specific day.
+    ///
+    /// Negative values count backwards from the end of the month, i.e. `-1` is the last day of
+    /// the month, `-2` the day before that and so on. They are resolved separately for every
+    /// month.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different days.
+    /// Alternatively, you can use [`.days()`][Cron::days] to feed days from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_day`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the day is zero, too small or too big. The minimum value is `-31`. The
+    /// maximum value is `31`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Event, ToSeries, pattern::cron};
+    ///
+    /// // At noon three days before the end of each month.
+    /// let pattern = cron().day(-4).hour(12).minute(0).second(0);
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 28).at(12, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 2, 25).at(12, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 3, 28).at(12, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 4, 27).at(12, 0, 0, 0))));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn day(self, day: i8) -> Cron {
+        self.try_day(day).expect("value for day is out of bounds")
+    }
+
+    /// Limit the pattern to the last day of the month.
+    ///
+    /// This is equivalent to calling [`.day(-1)`][Cron::day].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Event, ToSeries, pattern::cron};
+    ///
+    /// let pattern = cron().last_day().hour(0).minute(0).second(0);
+    ///
+    /// let series = date(2024, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2024, 1, 31).at(0, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2024, 2, 29).at(0, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2024, 3, 31).at(0, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2024, 4, 30).at(0, 0, 0, 0))));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn last_day(self) -> Cron {
+        self.day(-1)
+    }
+
+    /// Limit the pattern to the weekday (Monday to Friday) nearest to a specific day.
+    ///
+    /// If the day falls on a Saturday, the pattern ticks on the Friday before. If it falls on a
+    /// Sunday, the pattern ticks on the Monday after. The nearest weekday never crosses a month
+    /// boundary: if the 1st is a Saturday, the pattern ticks on Monday the 3rd, and if the last
+    /// day of the month is a Sunday, it ticks on the Friday before. Months which don't have the
+    /// given day are skipped.
+    ///
+    /// Like with [`.day()`][Cron::day], negative values count backwards from the end of the
+    /// month, so `-1` is the last weekday of the month.
+    ///
+    /// Nearest weekdays are part of the day-of-month field, which is combined with the
+    /// day-of-week field according to [`.day_match()`][Cron::day_match].
+    ///
+    /// The fallible version of this method is [`Cron::try_nearest_weekday`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the day is zero, too small or too big. The minimum value is `-31`. The
+    /// maximum value is `31`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Event, ToSeries, pattern::cron};
+    ///
+    /// // At 09:00 on the weekday nearest to the 15th of each month.
+    /// let pattern = cron().nearest_weekday(15).hour(9).minute(0).second(0);
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 15).at(9, 0, 0, 0))));
+    /// // The 15th of February 2025 is a Saturday.
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 2, 14).at(9, 0, 0, 0))));
+    /// // The 15th of March 2025 is a Saturday too.
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 3, 14).at(9, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 4, 15).at(9, 0, 0, 0))));
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn nearest_weekday(self, day: i8) -> Cron {
+        self.try_nearest_weekday(day)
+            .expect("value for nearest weekday is out of bounds")
+    }
+
+    /// Limit the days in the pattern to every `step`'s day from `start` onwards.
+    ///
+    /// The fallible version of this method is [`Cron::try_day_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `1`. The maximum start value is `31`.
+    #[must_use]
+    pub fn day_step_by(self, start: i8, step: usize) -> Cron {
+        self.try_day_step_by(start, step)
+            .expect("value for day step is out of bounds")
+    }
+
+    /// Limit the days in the pattern to specific values from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_days`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the day values produced by the iterator is day is zero, too small
+    /// or too big. The minimum value is `-31`. The maximum value is `31`. See
+    /// [`.day()`][Cron::day] for the meaning of negative values.
+    #[must_use]
+    pub fn days<I: IntoIterator<Item = i8>>(self, days: I) -> Cron {
+        self.try_days(days)
+            .expect("value for days is out of bounds")
+    }
+
+    /// Limit the days in the pattern to every `step`'s day within `range`.
+    ///
+    /// The fallible version of this method is [`Cron::try_days_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `1`. The maximum value is `31`.
+    #[must_use]
+    pub fn days_step_by(self, range: RangeInclusive<i8>, step: usize) -> Cron {
+        self.try_days_step_by(range, step)
+            .expect("value for days step is out of bounds")
+    }
+
+    /// Exclude a specific day from the pattern.
+    ///
+    /// The day is excluded regardless of whether the pattern is limited to specific days before
+    /// or after calling this method. Negative values exclude days counted from the end of the
+    /// month, see [`.day()`][Cron::day]. Excluded days never match, even if they are selected by
+    /// counting from the other end of the month or via
+    /// [`.nearest_weekday()`][Cron::nearest_weekday]. A pattern whose days are all excluded never
+    /// matches.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_day`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the day is zero, too small or too big. The minimum value is `-31`. The
+    /// maximum value is `31`.
+    #[must_use]
+    pub fn exclude_day(self, day: i8) -> Cron {
+        self.try_exclude_day(day)
+            .expect("value for day is out of bounds")
+    }
+
+    /// Exclude specific days produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_day()`][Cron::exclude_day] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_days`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the day values produced by the iterator is zero, too small or too
+    /// big. The minimum value is `-31`. The maximum value is `31`.
+    #[must_use]
+    pub fn exclude_days<I: IntoIterator<Item = i8>>(self, days: I) -> Cron {
+        self.try_exclude_days(days)
+            .expect("value for days is out of bounds")
+    }
+
+    /// Limit the pattern to a specific day of the year.
+    ///
+    /// The first day of the year is `1`. Day `366` only exists in leap years. Days of the year
+    /// are always combined with the day-of-month and day-of-week fields like months, regardless
+    /// of the [`DayMatch`].
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different days
+    /// of the year. Alternatively, you can use [`.days_of_year()`][Cron::days_of_year] to feed
+    /// days from an iterator.
+    ///
+    /// Cron expressions have no day-of-year field, see the `Display` implementation.
+    ///
+    /// The fallible version of this method is [`Cron::try_day_of_year`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the day is too small or too big. The minimum value is `1`. The maximum
+    /// value is `366`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Pattern, pattern::cron};
+    ///
+    /// // At midnight on the 100th day of the year.
+    /// let pattern = cron().day_of_year(100).hour(0).minute(0).second(0);
+    /// let range = date(2025, 1, 1).at(0, 0, 0, 0)..date(2026, 1, 1).at(0, 0, 0, 0);
+    ///
+    /// assert_eq!(
+    ///     pattern.next_after(date(2025, 1, 1).at(0, 0, 0, 0), range.into()),
+    ///     Some(date(2025, 4, 10).at(0, 0, 0, 0)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn day_of_year(self, day: i16) -> Cron {
+        self.try_day_of_year(day)
+            .expect("value for day of year is out of bounds")
+    }
+
+    /// Limit the days of the year in the pattern to specific values from an iterator.
+    ///
+    /// See [`.day_of_year()`][Cron::day_of_year] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_days_of_year`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the day values produced by the iterator is too small or too big.
+    /// The minimum value is `1`. The maximum value is `366`.
+    #[must_use]
+    pub fn days_of_year<I: IntoIterator<Item = i16>>(self, days: I) -> Cron {
+        self.try_days_of_year(days)
+            .expect("value for days of year is out of bounds")
+    }
+
+    /// Limit the pattern to a specific ISO 8601 week of the year.
+    ///
+    /// ISO weeks start on Monday and the first week of a year is the week containing its first
+    /// Thursday. Thus, the first days of January may belong to the last week of the previous year
+    /// and the last days of December to the first week of the next year. Week `53` only exists in
+    /// some years. ISO weeks are always combined with the day-of-month and day-of-week fields
+    /// like months, regardless of the [`DayMatch`].
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different weeks.
+    /// Alternatively, you can use [`.iso_weeks()`][Cron::iso_weeks] to feed weeks from an
+    /// iterator.
+    ///
+    /// Cron expressions have no ISO week field, see the `Display` implementation.
+    ///
+    /// The fallible version of this method is [`Cron::try_iso_week`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the week is too small or too big. The minimum value is `1`. The maximum
+    /// value is `53`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date};
+    /// use recurring::{Pattern, pattern::cron};
+    ///
+    /// // At midnight on Monday in ISO weeks 1 and 27.
+    /// let pattern = cron()
+    ///     .iso_weeks([1, 27])
+    ///     .weekday(Weekday::Monday)
+    ///     .hour(0).minute(0).second(0);
+    /// let range = date(2025, 1, 1).at(0, 0, 0, 0)..date(2027, 1, 1).at(0, 0, 0, 0);
+    ///
+    /// assert_eq!(
+    ///     pattern.next_after(date(2025, 1, 1).at(0, 0, 0, 0), range.into()),
+    ///     Some(date(2025, 6, 30).at(0, 0, 0, 0)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn iso_week(self, week: i8) -> Cron {
+        self.try_iso_week(week)
+            .expect("value for ISO week is out of bounds")
+    }
+
+    /// Limit the ISO weeks in the pattern to specific values from an iterator.
+    ///
+    /// See [`.iso_week()`][Cron::iso_week] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_iso_weeks`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the week values produced by the iterator is too small or too big.
+    /// The minimum value is `1`. The maximum value is `53`.
+    #[must_use]
+    pub fn iso_weeks<I: IntoIterator<Item = i8>>(self, weeks: I) -> Cron {
+        self.try_iso_weeks(weeks)
+            .expect("value for ISO weeks is out of bounds")
+    }
+
+    /// Limit the pattern to a specific hour.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different hours.
+    /// Alternatively, you can use [`.hours()`][Cron::hours] to feed hours from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_hour`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the hour is too small or too big. The minimum value is `0`. The maximum
+    /// value is `23`.
+    #[must_use]
+    pub fn hour(self, hour: i8) -> Cron {
+        self.try_hour(hour)
+            .expect("value for hour is out of bounds")
+    }
+
+    /// Limit the hours in the pattern to every `step`'s hour from `start` onwards.
+    ///
+    /// The fallible version of this method is [`Cron::try_hour_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `0`. The maximum start value is `23`.
+    #[must_use]
+    pub fn hour_step_by(self, start: i8, step: usize) -> Cron {
+        self.try_hour_step_by(start, step)
+            .expect("value for hour step is out of bounds")
+    }
+
+    /// Limit the hours in the pattern to specific values from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_hours`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the hour values produced by the iterator is hour is too small
+    /// or too big. The minimum value is `0`. The maximum value is `23`.
+    #[must_use]
+    pub fn hours<I: IntoIterator<Item = i8>>(self, hours: I) -> Cron {
+        self.try_hours(hours)
+            .expect("value for hours is out of bounds")
+    }
+
+    /// Limit the hours in the pattern to every `step`'s hour within `range`.
+    ///
+    /// The fallible version of this method is [`Cron::try_hours_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `0`. The maximum value is `23`.
+    #[must_use]
+    pub fn hours_step_by(self, range: RangeInclusive<i8>, step: usize) -> Cron {
+        self.try_hours_step_by(range, step)
+            .expect("value for hours step is out of bounds")
+    }
+
+    /// Exclude a specific hour from the pattern.
+    ///
+    /// The hour is excluded regardless of whether the pattern is limited to specific hours
+    /// before or after calling this method. A pattern whose hours are all excluded never matches.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_hour`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the hour is too small or too big. The minimum value is `0`. The
+    /// maximum value is `23`.
+    #[must_use]
+    pub fn exclude_hour(self, hour: i8) -> Cron {
+        self.try_exclude_hour(hour)
+            .expect("value for hour is out of bounds")
+    }
+
+    /// Exclude specific hours produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_hour()`][Cron::exclude_hour] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_hours`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the hour values produced by the iterator is too small or too
+    /// big. The minimum value is `0`. The maximum value is `23`.
+    #[must_use]
+    pub fn exclude_hours<I: IntoIterator<Item = i8>>(self, hours: I) -> Cron {
+        self.try_exclude_hours(hours)
+            .expect("value for hours is out of bounds")
+    }
+
+    /// Limit the pattern to a specific minute.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different minutes.
+    /// Alternatively, you can use [`.minutes()`][Cron::minutes] to feed minutes from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_minute`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the minute is too small or too big. The minimum value is `0`. The maximum
+    /// value is `59`.
+    #[must_use]
+    pub fn minute(self, minute: i8) -> Cron {
+        self.try_minute(minute)
+            .expect("value for minute is out of bounds")
+    }
+
+    /// Limit the minutes in the pattern to every `step`'s minute from `start` onwards.
+    ///
+    /// The fallible version of this method is [`Cron::try_minute_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `0`. The maximum start value is `59`.
+    #[must_use]
+    pub fn minute_step_by(self, start: i8, step: usize) -> Cron {
+        self.try_minute_step_by(start, step)
+            .expect("value for minute step is out of bounds")
+    }
+
+    /// Limit the minutes in the pattern to specific values from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_minutes`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the minute values produced by the iterator is minute is too small
+    /// or too big. The minimum value is `0`. The maximum value is `59`.
+    #[must_use]
+    pub fn minutes<I: IntoIterator<Item = i8>>(self, minutes: I) -> Cron {
+        self.try_minutes(minutes)
+            .expect("value for minutes is out of bounds")
+    }
+
+    /// Limit the minutes in the pattern to every `step`'s minute within `range`.
+    ///
+    /// The fallible version of this method is [`Cron::try_minutes_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `0`. The maximum value is `59`.
+    #[must_use]
+    pub fn minutes_step_by(self, range: RangeInclusive<i8>, step: usize) -> Cron {
+        self.try_minutes_step_by(range, step)
+            .expect("value for minutes step is out of bounds")
+    }
+
+    /// Exclude a specific minute from the pattern.
+    ///
+    /// The minute is excluded regardless of whether the pattern is limited to specific minutes
+    /// before or after calling this method. A pattern whose minutes are all excluded never matches.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_minute`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the minute is too small or too big. The minimum value is `0`. The
+    /// maximum value is `59`.
+    #[must_use]
+    pub fn exclude_minute(self, minute: i8) -> Cron {
+        self.try_exclude_minute(minute)
+            .expect("value for minute is out of bounds")
+    }
+
+    /// Exclude specific minutes produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_minute()`][Cron::exclude_minute] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_minutes`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the minute values produced by the iterator is too small or too
+    /// big. The minimum value is `0`. The maximum value is `59`.
+    #[must_use]
+    pub fn exclude_minutes<I: IntoIterator<Item = i8>>(self, minutes: I) -> Cron {
+        self.try_exclude_minutes(minutes)
+            .expect("value for minutes is out of bounds")
+    }
+
+    /// Limit the pattern to a specific second.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different seconds.
+    /// Alternatively, you can use [`.seconds()`][Cron::seconds] to feed seconds from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_second`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the second is too small or too big. The minimum value is `0`. The maximum
+    /// value is `59`.
+    #[must_use]
+    pub fn second(self, second: i8) -> Cron {
+        self.try_second(second)
+            .expect("value for second is out of bounds")
+    }
+
+    /// Limit the seconds in the pattern to every `step`'s second from `start` onwards.
+    ///
+    /// The fallible version of this method is [`Cron::try_second_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `0`. The maximum start value is `59`.
+    #[must_use]
+    pub fn second_step_by(self, start: i8, step: usize) -> Cron {
+        self.try_second_step_by(start, step)
+            .expect("value for seconds step is out of bounds")
+    }
+
+    /// Limit the seconds in the pattern to specific values from an iterator.
+    ///
+    /// The fallible version of this method is [`Cron::try_seconds`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the second values produced by the iterator is second is too small
+    /// or too big. The minimum value is `0`. The maximum value is `59`.
+    #[must_use]
+    pub fn seconds<I: IntoIterator<Item = i8>>(self, seconds: I) -> Cron {
+        self.try_seconds(seconds)
+            .expect("value for seconds is out of bounds")
+    }
+
+    /// Limit the seconds in the pattern to every `step`'s second within `range`.
+    ///
+    /// The fallible version of this method is [`Cron::try_seconds_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `0`. The maximum value is `59`.
+    #[must_use]
+    pub fn seconds_step_by(self, range: RangeInclusive<i8>, step: usize) -> Cron {
+        self.try_seconds_step_by(range, step)
+            .expect("value for seconds step is out of bounds")
+    }
+
+    /// Exclude a specific second from the pattern.
+    ///
+    /// The second is excluded regardless of whether the pattern is limited to specific seconds
+    /// before or after calling this method. A pattern whose seconds are all excluded never matches.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_second`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the second is too small or too big. The minimum value is `0`. The
+    /// maximum value is `59`.
+    #[must_use]
+    pub fn exclude_second(self, second: i8) -> Cron {
+        self.try_exclude_second(second)
+            .expect("value for second is out of bounds")
+    }
+
+    /// Exclude specific seconds produced by an iterator from the pattern.
+    ///
+    /// See [`.exclude_second()`][Cron::exclude_second] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_exclude_seconds`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the second values produced by the iterator is too small or too
+    /// big. The minimum value is `0`. The maximum value is `59`.
+    #[must_use]
+    pub fn exclude_seconds<I: IntoIterator<Item = i8>>(self, seconds: I) -> Cron {
+        self.try_exclude_seconds(seconds)
+            .expect("value for seconds is out of bounds")
+    }
+
+    /// Limit the pattern to a specific millisecond within each second.
+    ///
+    /// By default, the pattern only ticks at the start of each second, i.e. at millisecond `0`.
+    /// Once a millisecond is configured, the pattern ticks at the configured milliseconds only.
+    /// This method can be called multiple times to limit the pattern to multiple different
+    /// milliseconds. Alternatively, you can use [`.milliseconds()`][Cron::milliseconds] to feed
+    /// milliseconds from an iterator.
+    ///
+    /// Milliseconds are not part of cron expressions, see the `Display` implementation.
+    ///
+    /// The fallible version of this method is [`Cron::try_millisecond`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when the millisecond is too small or too big. The minimum value is `0`. The
+    /// maximum value is `999`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Pattern, pattern::cron};
+    ///
+    /// // Every second at .250 and .750.
+    /// let pattern = cron().millisecond(250).millisecond(750);
+    /// let range = date(2025, 1, 1).at(0, 0, 0, 0)..date(2026, 1, 1).at(0, 0, 0, 0);
+    ///
+    /// assert_eq!(
+    ///     pattern.next_after(date(2025, 1, 1).at(12, 0, 0, 500_000_000), range.into()),
+    ///     Some(date(2025, 1, 1).at(12, 0, 0, 750_000_000)),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn millisecond(self, millisecond: i16) -> Cron {
+        self.try_millisecond(millisecond)
+            .expect("value for millisecond is out of bounds")
+    }
+
+    /// Limit the milliseconds in the pattern to specific values from an iterator.
+    ///
+    /// See [`.millisecond()`][Cron::millisecond] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_milliseconds`].
+    ///
+    /// # Panics
+    ///
+    /// This panics when any of the millisecond values produced by the iterator is too small or
+    /// too big. The minimum value is `0`. The maximum value is `999`.
+    #[must_use]
+    pub fn milliseconds<I: IntoIterator<Item = i16>>(self, milliseconds: I) -> Cron {
+        self.try_milliseconds(milliseconds)
+            .expect("value for milliseconds is out of bounds")
+    }
+
+    /// Limit the milliseconds in the pattern to every `step`'s millisecond within `range`.
+    ///
+    /// See [`.millisecond()`][Cron::millisecond] for details.
+    ///
+    /// The fallible version of this method is [`Cron::try_milliseconds_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// This panics if the given step is `0`, if the range is empty or if its bounds are too small
+    /// or too big. The minimum value is `0`. The maximum value is `999`.
+    #[must_use]
+    pub fn milliseconds_step_by(self, range: RangeInclusive<i16>, step: usize) -> Cron {
+        self.try_milliseconds_step_by(range, step)
+            .expect("value for milliseconds step is out of bounds")
+    }
+}
+
+// Fallible builder methods.
+impl Cron {
+    /// Limit the pattern to a specific year.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different years.
+    /// Alternatively, you can use [`.try_years()`][Cron::try_years] to feed years from an
+    /// iterator.
+    ///
+    /// The panicking version of this method is [`Cron::year`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the year is too small or too big. The minimum value is `-9999`.
+    /// The maximum value is `9999`.
+    pub fn try_year(mut self, year: i16) -> Result<Cron, Error> {
+        self.years.try_insert(year)?;
+        Ok(self)
+    }
+
+    /// Limit the years in the pattern to every `step`'s year from `start` onwards.
+    ///
+    /// # Errors
+    ///
+    /// The returns an error if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `-9999`. The maximum start value is `9999`.
+    ///
+    /// The panicking version of this method is [`Cron::year_step_by`].
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    pub fn try_year_step_by(self, start: i16, step: usize) -> Result<Cron, Error> {
+        (start..=Years::MAX)
+            .step_by(step)
+            .try_fold(self, Cron::try_year)
+    }
+
+    /// Limit the years in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::years`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the year values produced by the iterator is too small or
+    /// too big. The minimum value is `-9999`. The maximum value is `9999`.
+    pub fn try_years<I: IntoIterator<Item = i16>>(self, years: I) -> Result<Cron, Error> {
+        years.into_iter().try_fold(self, Cron::try_year)
+    }
+
+    /// Limit the years in the pattern to every `step`'s year within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::years_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `-9999`. The maximum value is `9999`.
+    pub fn try_years_step_by(self, range: RangeInclusive<i16>, step: usize) -> Result<Cron, Error> {
+        check_step_range(&range, step, Years::MIN, Years::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_year)
+    }
+
+    /// Exclude a specific year from the pattern.
+    ///
+    /// See [`.exclude_year()`][Cron::exclude_year] for details.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_year`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the year is too small or too big. The minimum value is `-9999`.
+    /// The maximum value is `9999`.
+    pub fn try_exclude_year(mut self, year: i16) -> Result<Cron, Error> {
+        self.years.try_exclude(year)?;
+        Ok(self)
+    }
+
+    /// Exclude specific years produced by an iterator from the pattern.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_years`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the year values produced by the iterator is too small
+    /// or too big. The minimum value is `-9999`. The maximum value is `9999`.
+    pub fn try_exclude_years<I: IntoIterator<Item = i16>>(self, years: I) -> Result<Cron, Error> {
+        years.into_iter().try_fold(self, Cron::try_exclude_year)
+    }
+
+    /// Limit the pattern to a specific month.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different months.
+    /// Alternatively, you can use [`.months()`][Cron::months] to feed months from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::month`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the month is too small or too big. The minimum value is `1`.
+    /// The maximum value is `12`.
+    pub fn try_month(mut self, month: i8) -> Result<Cron, Error> {
+        self.months.try_insert(month)?;
+        Ok(self)
+    }
+
+    /// Limit the months in the pattern to every `step`'s month from `start` onwards.
+    ///
+    /// The panicking version of this method is [`Cron::month_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// The returns an error if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `1`. The maximum start value is `12`.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    pub fn try_month_step_by(self, start: i8, step: usize) -> Result<Cron, Error> {
+        (start..=Months::MAX)
+            .step_by(step)
+            .try_fold(self, Cron::try_month)
+    }
+
+    /// Limit the months in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::months`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the month values produced by the iterator is month is
+    /// too small or too big. The minimum value is `1`. The maximum value is `12`.
+    pub fn try_months<I: IntoIterator<Item = i8>>(self, months: I) -> Result<Cron, Error> {
+        months.into_iter().try_fold(self, Cron::try_month)
+    }
+
+    /// Limit the months in the pattern to every `step`'s month within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::months_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `1`. The maximum value is `12`.
+    pub fn try_months_step_by(self, range: RangeInclusive<i8>, step: usize) -> Result<Cron, Error> {
+        check_step_range(&range, step, Months::MIN, Months::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_month)
+    }
+
+    /// Exclude a specific month from the pattern.
+    ///
+    /// See [`.exclude_month()`][Cron::exclude_month] for details.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_month`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the month is too small or too big. The minimum value is `1`.
+    /// The maximum value is `12`.
+    pub fn try_exclude_month(mut self, month: i8) -> Result<Cron, Error> {
+        self.months.try_exclude(month)?;
+        Ok(self)
+    }
+
+    /// Exclude specific months produced by an iterator from the pattern.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_months`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the month values produced by the iterator is too small
+    /// or too big. The minimum value is `1`. The maximum value is `12`.
+    pub fn try_exclude_months<I: IntoIterator<Item = i8>>(self, months: I) -> Result<Cron, Error> {
+        months.into_iter().try_fold(self, Cron::try_exclude_month)
+    }
+
+    /// Limit the weekdays in the pattern to every `step`'s weekday within `range`.
+    ///
+    /// See [`.weekdays_step_by()`][Cron::weekdays_step_by] for details.
+    ///
+    /// The panicking version of this method is [`Cron::weekdays_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0` or if the range is empty.
+    pub fn try_weekdays_step_by(
+        self,
+        range: RangeInclusive<Weekday>,
+        step: usize,
+    ) -> Result<Cron, Error> {
+        let (start, end) = range.into_inner();
+        let range = start.to_monday_one_offset()..=end.to_monday_one_offset();
+        check_step_range(&range, step, Weekdays::MIN, Weekdays::MAX)?;
+        Ok(range.step_by(step).fold(self, Cron::weekday_i8))
+    }
+
+    /// Limit the pattern to the `nth` occurrence of a weekday within the month.
+    ///
+    /// See [`.nth_weekday()`][Cron::nth_weekday] for details.
+    ///
+    /// The panicking version of this method is [`Cron::nth_weekday`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when `nth` is zero, too small or too big. The minimum value is `-5`.
+    /// The maximum value is `5`.
+    pub fn try_nth_weekday(mut self, nth: i8, weekday: Weekday) -> Result<Cron, Error> {
+        self.nth_weekdays.try_insert(nth, weekday)?;
+        Ok(self)
+    }
+
+    /// Limit the pattern to a specific day.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different days.
+    /// Alternatively, you can use [`.days()`][Cron::days] to feed days from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::day`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the day is zero, too small or too big. The minimum value is
+    /// `-31`. The maximum value is `31`. See [`.day()`][Cron::day] for the meaning of negative
+    /// values.
+    pub fn try_day(mut self, day: i8) -> Result<Cron, Error> {
+        match day {
+            0 => return Err(err!("day must not be zero")),
+            day if day < 0 => {
+                self.last_days
+                    .try_insert(day.checked_neg().unwrap_or(i8::MAX))
+                    .map_err(|_| Error::range(day, -Days::MAX, Days::MAX))?;
+            }
+            day => {
+                self.days
+                    .try_insert(day)
+                    .map_err(|_| Error::range(day, -Days::MAX, Days::MAX))?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Limit the pattern to the weekday (Monday to Friday) nearest to a specific day.
+    ///
+    /// See [`.nearest_weekday()`][Cron::nearest_weekday] for details.
+    ///
+    /// The panicking version of this method is [`Cron::nearest_weekday`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the day is zero, too small or too big. The minimum value is
+    /// `-31`. The maximum value is `31`.
+    pub fn try_nearest_weekday(mut self, day: i8) -> Result<Cron, Error> {
+        self.nearest_weekdays.try_insert(day)?;
+        Ok(self)
+    }
+
+    /// Limit the days in the pattern to every `step`'s day from `start` onwards.
+    ///
+    /// The panicking version of this method is [`Cron::day_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// The returns an error if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `1`. The maximum start value is `31`.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    pub fn try_day_step_by(self, start: i8, step: usize) -> Result<Cron, Error> {
+        if start < Days::MIN {
+            return Err(Error::range(start, Days::MIN, Days::MAX));
+        }
+
+        (start..=Days::MAX)
+            .step_by(step)
+            .try_fold(self, Cron::try_day)
+    }
+
+    /// Limit the days in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::days`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the day values produced by the iterator is day is
+    /// zero, too small or too big. The minimum value is `-31`. The maximum value is `31`. See
+    /// [`.day()`][Cron::day] for the meaning of negative values.
+    pub fn try_days<I: IntoIterator<Item = i8>>(self, days: I) -> Result<Cron, Error> {
+        days.into_iter().try_fold(self, Cron::try_day)
+    }
+
+    /// Limit the days in the pattern to every `step`'s day within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::days_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `1`. The maximum value is `31`.
+    pub fn try_days_step_by(self, range: RangeInclusive<i8>, step: usize) -> Result<Cron, Error> {
+        check_step_range(&range, step, Days::MIN, Days::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_day)
+    }
+
+    /// Exclude a specific day from the pattern.
+    ///
+    /// See [`.exclude_day()`][Cron::exclude_day] for details.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_day`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the day is zero, too small or too big. The minimum value is
+    /// `-31`. The maximum value is `31`.
+    pub fn try_exclude_day(mut self, day: i8) -> Result<Cron, Error> {
+        match day {
+            0 => return Err(err!("day must not be zero")),
+            day if day < 0 => {
+                self.last_days
+                    .try_exclude(day.checked_neg().unwrap_or(i8::MAX))
+                    .map_err(|_| Error::range(day, -Days::MAX, Days::MAX))?;
+            }
+            day => {
+                self.days
+                    .try_exclude(day)
+                    .map_err(|_| Error::range(day, -Days::MAX, Days::MAX))?;
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Exclude specific days produced by an iterator from the pattern.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_days`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the day values produced by the iterator is zero, too
+    /// small or too big. The minimum value is `-31`. The maximum value is `31`.
+    pub fn try_exclude_days<I: IntoIterator<Item = i8>>(self, days: I) -> Result<Cron, Error> {
+        days.into_iter().try_fold(self, Cron::try_exclude_day)
+    }
+
+    /// Limit the pattern to a specific day of the year.
+    ///
+    /// See [`.day_of_year()`][Cron::day_of_year] for details.
+    ///
+    /// The panicking version of this method is [`Cron::day_of_year`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the day is too small or too big. The minimum value is `1`.
+    /// The maximum value is `366`.
+    pub fn try_day_of_year(mut self, day: i16) -> Result<Cron, Error> {
+        self.days_of_year.try_insert(day)?;
+        Ok(self)
+    }
+
+    /// Limit the days of the year in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::days_of_year`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the day values produced by the iterator is too small or
+    /// too big. The minimum value is `1`. The maximum value is `366`.
+    pub fn try_days_of_year<I: IntoIterator<Item = i16>>(self, days: I) -> Result<Cron, Error> {
+        days.into_iter().try_fold(self, Cron::try_day_of_year)
+    }
+
+    /// Limit the pattern to a specific ISO 8601 week of the year.
+    ///
+    /// See [`.iso_week()`][Cron::iso_week] for details.
+    ///
+    /// The panicking version of this method is [`Cron::iso_week`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the week is too small or too big. The minimum value is `1`.
+    /// The maximum value is `53`.
+    pub fn try_iso_week(mut self, week: i8) -> Result<Cron, Error> {
+        self.iso_weeks.try_insert(week)?;
+        Ok(self)
+    }
+
+    /// Limit the ISO weeks in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::iso_weeks`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the week values produced by the iterator is too small or
+    /// too big. The minimum value is `1`. The maximum value is `53`.
+    pub fn try_iso_weeks<I: IntoIterator<Item = i8>>(self, weeks: I) -> Result<Cron, Error> {
+        weeks.into_iter().try_fold(self, Cron::try_iso_week)
+    }
+
+    /// Limit the pattern to a specific hour.
+    ///
+    /// The panicking version of this method is [`Cron::hour`].
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different hours.
+    /// Alternatively, you can use [`.hours()`][Cron::hours] to feed hours from an iterator.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the hour is too small or too big. The minimum value is `0`.
+    /// The maximum value is `23`.
+    pub fn try_hour(mut self, hour: i8) -> Result<Cron, Error> {
+        self.hours.try_insert(hour)?;
+        Ok(self)
+    }
+
+    /// Limit the hours in the pattern to every `step`'s hour from `start` onwards.
+    ///
+    /// The panicking version of this method is [`Cron::hour_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// The returns an error if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `0`. The maximum start value is `23`.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    pub fn try_hour_step_by(self, start: i8, step: usize) -> Result<Cron, Error> {
+        (start..=Hours::MAX)
+            .step_by(step)
+            .try_fold(self, Cron::try_hour)
+    }
+
+    /// Limit the hours in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::hours`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the hour values produced by the iterator is hour is
+    /// too small or too big. The minimum value is `0`. The maximum value is `23`.
+    pub fn try_hours<I: IntoIterator<Item = i8>>(self, hours: I) -> Result<Cron, Error> {
+        hours.into_iter().try_fold(self, Cron::try_hour)
+    }
+
+    /// Limit the hours in the pattern to every `step`'s hour within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::hours_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `0`. The maximum value is `23`.
+    pub fn try_hours_step_by(self, range: RangeInclusive<i8>, step: usize) -> Result<Cron, Error> {
+        check_step_range(&range, step, Hours::MIN, Hours::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_hour)
+    }
+
+    /// Exclude a specific hour from the pattern.
+    ///
+    /// See [`.exclude_hour()`][Cron::exclude_hour] for details.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_hour`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the hour is too small or too big. The minimum value is `0`.
+    /// The maximum value is `23`.
+    pub fn try_exclude_hour(mut self, hour: i8) -> Result<Cron, Error> {
+        self.hours.try_exclude(hour)?;
+        Ok(self)
+    }
+
+    /// Exclude specific hours produced by an iterator from the pattern.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_hours`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the hour values produced by the iterator is too small
+    /// or too big. The minimum value is `0`. The maximum value is `23`.
+    pub fn try_exclude_hours<I: IntoIterator<Item = i8>>(self, hours: I) -> Result<Cron, Error> {
+        hours.into_iter().try_fold(self, Cron::try_exclude_hour)
+    }
+
+    /// Limit the pattern to a specific minute.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different minutes.
+    /// Alternatively, you can use [`.minutes()`][Cron::minutes] to feed minutes from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::minute`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the minute is too small or too big. The minimum value is `0`.
+    /// The maximum value is `59`.
+    pub fn try_minute(mut self, minute: i8) -> Result<Cron, Error> {
+        self.minutes.try_insert(minute)?;
+        Ok(self)
+    }
+
+    /// Limit the minutes in the pattern to every `step`'s minute from `start` onwards.
+    ///
+    /// The panicking version of this method is [`Cron::minute_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// The returns an error if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `0`. The maximum start value is `59`.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    pub fn try_minute_step_by(self, start: i8, step: usize) -> Result<Cron, Error> {
+        (start..=Minutes::MAX)
+            .step_by(step)
+            .try_fold(self, Cron::try_minute)
+    }
+
+    /// Limit the minutes in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::minutes`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the minute values produced by the iterator is minute is
+    /// too small or too big. The minimum value is `0`. The maximum value is `59`.
+    pub fn try_minutes<I: IntoIterator<Item = i8>>(self, minutes: I) -> Result<Cron, Error> {
+        minutes.into_iter().try_fold(self, Cron::try_minute)
+    }
+
+    /// Limit the minutes in the pattern to every `step`'s minute within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::minutes_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `0`. The maximum value is `59`.
+    pub fn try_minutes_step_by(
+        self,
+        range: RangeInclusive<i8>,
+        step: usize,
+    ) -> Result<Cron, Error> {
+        check_step_range(&range, step, Minutes::MIN, Minutes::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_minute)
+    }
+
+    /// Exclude a specific minute from the pattern.
+    ///
+    /// See [`.exclude_minute()`][Cron::exclude_minute] for details.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_minute`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the minute is too small or too big. The minimum value is `0`.
+    /// The maximum value is `59`.
+    pub fn try_exclude_minute(mut self, minute: i8) -> Result<Cron, Error> {
+        self.minutes.try_exclude(minute)?;
+        Ok(self)
+    }
+
+    /// Exclude specific minutes produced by an iterator from the pattern.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_minutes`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the minute values produced by the iterator is too small
+    /// or too big. The minimum value is `0`. The maximum value is `59`.
+    pub fn try_exclude_minutes<I: IntoIterator<Item = i8>>(
+        self,
+        minutes: I,
+    ) -> Result<Cron, Error> {
+        minutes.into_iter().try_fold(self, Cron::try_exclude_minute)
+    }
+
+    /// Limit the pattern to a specific second.
+    ///
+    /// This method can be called multiple times to limit the pattern to multiple different seconds.
+    /// Alternatively, you can use [`.seconds()`][Cron::seconds] to feed seconds from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::second`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the second is too small or too big. The minimum value is `0`.
+    /// The maximum value is `59`.
+    pub fn try_second(mut self, second: i8) -> Result<Cron, Error> {
+        self.seconds.try_insert(second)?;
+        Ok(self)
+    }
+
+    /// Limit the seconds in the pattern to every `step`'s second from `start` onwards.
+    ///
+    /// The panicking version of this method is [`Cron::second_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// The returns an error if the given step is `0` or if start is too small or too big. The
+    /// minimum start value is `0`. The maximum start value is `59`.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the given step is `0`.
+    pub fn try_second_step_by(self, start: i8, step: usize) -> Result<Cron, Error> {
+        (start..=Seconds::MAX)
+            .step_by(step)
+            .try_fold(self, Cron::try_second)
+    }
+
+    /// Limit the seconds in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::seconds`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the second values produced by the iterator is second is
+    /// too small or too big. The minimum value is `0`. The maximum value is `59`.
+    pub fn try_seconds<I: IntoIterator<Item = i8>>(self, seconds: I) -> Result<Cron, Error> {
+        seconds.into_iter().try_fold(self, Cron::try_second)
+    }
+
+    /// Limit the seconds in the pattern to every `step`'s second within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::seconds_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `0`. The maximum value is `59`.
+    pub fn try_seconds_step_by(
+        self,
+        range: RangeInclusive<i8>,
+        step: usize,
+    ) -> Result<Cron, Error> {
+        check_step_range(&range, step, Seconds::MIN, Seconds::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_second)
+    }
+
+    /// Exclude a specific second from the pattern.
+    ///
+    /// See [`.exclude_second()`][Cron::exclude_second] for details.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_second`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the second is too small or too big. The minimum value is `0`.
+    /// The maximum value is `59`.
+    pub fn try_exclude_second(mut self, second: i8) -> Result<Cron, Error> {
+        self.seconds.try_exclude(second)?;
+        Ok(self)
+    }
+
+    /// Exclude specific seconds produced by an iterator from the pattern.
+    ///
+    /// The panicking version of this method is [`Cron::exclude_seconds`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the second values produced by the iterator is too small
+    /// or too big. The minimum value is `0`. The maximum value is `59`.
+    pub fn try_exclude_seconds<I: IntoIterator<Item = i8>>(
+        self,
+        seconds: I,
+    ) -> Result<Cron, Error> {
+        seconds.into_iter().try_fold(self, Cron::try_exclude_second)
+    }
+
+    /// Limit the pattern to a specific millisecond within each second.
+    ///
+    /// See [`.millisecond()`][Cron::millisecond] for details.
+    ///
+    /// The panicking version of this method is [`Cron::millisecond`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when the millisecond is too small or too big. The minimum value is
+    /// `0`. The maximum value is `999`.
+    pub fn try_millisecond(mut self, millisecond: i16) -> Result<Cron, Error> {
+        self.milliseconds
+            .get_or_insert_with(Milliseconds::default)
+            .try_insert(millisecond)?;
+        Ok(self)
+    }
+
+    /// Limit the milliseconds in the pattern to specific values from an iterator.
+    ///
+    /// The panicking version of this method is [`Cron::milliseconds`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error when any of the millisecond values produced by the iterator is too
+    /// small or too big. The minimum value is `0`. The maximum value is `999`.
+    pub fn try_milliseconds<I: IntoIterator<Item = i16>>(
+        self,
+        milliseconds: I,
+    ) -> Result<Cron, Error> {
+        milliseconds
+            .into_iter()
+            .try_fold(self, Cron::try_millisecond)
+    }
+
+    /// Limit the milliseconds in the pattern to every `step`'s millisecond within `range`.
+    ///
+    /// The panicking version of this method is [`Cron::milliseconds_step_by`].
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the given step is `0`, if the range is empty or if its bounds are
+    /// too small or too big. The minimum value is `0`. The maximum value is `999`.
+    pub fn try_milliseconds_step_by(
+        self,
+        range: RangeInclusive<i16>,
+        step: usize,
+    ) -> Result<Cron, Error> {
+        check_step_range(&range, step, Milliseconds::MIN, Milliseconds::MAX)?;
+        range.step_by(step).try_fold(self, Cron::try_millisecond)
+    }
+}
+
+impl Cron {
+    fn next_after_or_current(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let mut clamp = DateTimeClamp::new(instant);
+        let either = self.matches_either_day();
+        let mut shapes = None;
+
+        for year in self.years.range(clamp.year..=Years::MAX) {
+            if year > instant.year() {
+                clamp.months_to_min();
+
+                // The search continues beyond the initial year. Stop early if the pattern never
+                // matches, otherwise skip months which can't match below.
+                if shapes.is_none() {
+                    shapes = Some(self.satisfiable_month_shapes()?);
+                }
+            }
+
+            let month_start = clamp.month;
+            if !self.months.contains(month_start) {
+                clamp.months_to_min();
+            }
+
+            for month in self.months.range(month_start..=Months::MAX) {
+                if shapes.is_some_and(|shapes| !shapes.contains(year, month)) {
+                    continue;
+                }
+
+                let day_start = clamp.day;
+                if !self.matches_day(year, month, day_start, either) {
+                    clamp.days_to_min();
+                }
+
+                let day_end = days_in_month(month, year);
+                let days =
+                    (day_start..=day_end).filter(|&day| self.matches_day(year, month, day, either));
+
+                for day in days {
+                    let hour_start = clamp.hour;
+                    if !self.hours.contains(clamp.hour) {
+                        clamp.hours_to_min();
+                    }
+
+                    for hour in self.hours.range(hour_start..=Hours::MAX) {
+                        let minute_start = clamp.minute;
+                        if !self.minutes.contains(minute_start) {
+                            clamp.minutes_to_min();
+                        }
+
+                        for minute in self.minutes.range(minute_start..=Minutes::MAX) {
+                            let second_start = clamp.second;
+                            if !self.seconds.contains(second_start) {
+                                clamp.seconds_to_min();
+                            }
+
+                            for second in self.seconds.range(second_start..=Seconds::MAX) {
+                                let millisecond_start = clamp.millisecond;
+                                if !self.matches_millisecond(millisecond_start) {
+                                    clamp.milliseconds_to_min();
+                                }
+
+                                let millisecond = self
+                                    .millisecond_range(millisecond_start..=Milliseconds::MAX)
+                                    .next();
+
+                                let Some(millisecond) = millisecond else {
+                                    clamp.seconds_to_min();
+                                    continue;
+                                };
+
+                                let nanosecond = i32::from(millisecond) * 1_000_000;
+                                let Ok(date) = DateTime::new(
+                                    year, month, day, hour, minute, second, nanosecond,
+                                ) else {
+                                    continue;
+                                };
+
+                                if range.contains(&date) {
+                                    return Some(date);
+                                }
+                                return None;
+                            }
+
+                            clamp.minutes_to_min();
+                        }
+
+                        clamp.hours_to_min();
+                    }
+
+                    clamp.days_to_min();
+                }
+
+                clamp.months_to_min();
+            }
+        }
+
+        None
+    }
+
+    fn previous_before_or_current(
+        &self,
+        instant: DateTime,
+        range: DateTimeRange,
+    ) -> Option<DateTime> {
+        let mut clamp = DateTimeClamp::new(instant);
+        let either = self.matches_either_day();
+        let mut shapes = None;
+
+        for year in self.years.range(Years::MIN..=clamp.year).rev() {
+            if year < instant.year() {
+                clamp.months_to_max();
+
+                // See `next_after_or_current`.
+                if shapes.is_none() {
+                    shapes = Some(self.satisfiable_month_shapes()?);
+                }
+            }
+
+            let month_end = clamp.month;
+            if !self.months.contains(month_end) {
+                clamp.months_to_max();
+            }
+
+            for month in self.months.range(Months::MIN..=month_end).rev() {
+                if shapes.is_some_and(|shapes| !shapes.contains(year, month)) {
+                    continue;
+                }
+
+                let day_end = days_in_month(month, year).min(clamp.day);
+                if day_end < clamp.day || !self.matches_day(year, month, day_end, either) {
+                    clamp.days_to_max();
+                }
+
+                let days = (Days::MIN..=day_end)
+                    .rev()
+                    .filter(|&day| self.matches_day(year, month, day, either));
+
+                for day in days {
+                    let hour_end = clamp.hour;
+                    if !self.hours.contains(clamp.hour) {
+                        clamp.hours_to_max();
+                    }
+
+                    for hour in self.hours.range(Hours::MIN..=hour_end).rev() {
+                        let minute_end = clamp.minute;
+                        if !self.minutes.contains(minute_end) {
+                            clamp.minutes_to_max();
+                        }
+
+                        for minute in self.minutes.range(Minutes::MIN..=minute_end).rev() {
+                            let second_end = clamp.second;
+                            if !self.seconds.contains(second_end) {
+                                clamp.seconds_to_max();
+                            }
+
+                            for second in self.seconds.range(Seconds::MIN..=second_end).rev() {
+                                let millisecond_end = clamp.millisecond;
+                                if !self.matches_millisecond(millisecond_end) {
+                                    clamp.milliseconds_to_max();
+                                }
+
+                                let millisecond = self
+                                    .millisecond_range(Milliseconds::MIN..=millisecond_end)
+                                    .next_back();
+
+                                let Some(millisecond) = millisecond else {
+                                    clamp.seconds_to_max();
+                                    continue;
+                                };
+
+                                let nanosecond = i32::from(millisecond) * 1_000_000;
+                                let Ok(date) = DateTime::new(
+                                    year, month, day, hour, minute, second, nanosecond,
+                                ) else {
+                                    continue;
+                                };
+
+                                if range.contains(&date) {
+                                    return Some(date);
+                                }
+                                return None;
+                            }
+
+                            clamp.minutes_to_max();
+                        }
+
+                        clamp.hours_to_max();
+                    }
+
+                    clamp.days_to_max();
+                }
+
+                clamp.months_to_max();
+            }
+        }
+
+        None
+    }
+
+    /// Returns the shapes of months which contain a matching day, or `None` if no month of any
+    /// of the configured years has such a shape.
+    fn satisfiable_month_shapes(&self) -> Option<MonthShapes> {
+        // Fields from which all values were excluded never match.
+        if self
+            .seconds
+            .range(Seconds::MIN..=Seconds::MAX)
+            .next()
+            .is_none()
+            || self
+                .minutes
+                .range(Minutes::MIN..=Minutes::MAX)
+                .next()
+                .is_none()
+            || self.hours.range(Hours::MIN..=Hours::MAX).next().is_none()
+        {
+            return None;
+        }
+
+        let shapes = MonthShapes::new(self);
+
+        let satisfiable = if self.years.is_full() {
+            self.months
+                .range(Months::MIN..=Months::MAX)
+                .any(|month| shapes.contains_month(month))
+        } else {
+            self.years.range(Years::MIN..=Years::MAX).any(|year| {
+                self.months
+                    .range(Months::MIN..=Months::MAX)
+                    .any(|month| shapes.contains(year, month))
+            })
+        };
+
+        satisfiable.then_some(shapes)
+    }
+
+    /// Returns the milliseconds within `range` the pattern ticks at.
+    fn millisecond_range(&self, range: RangeInclusive<i16>) -> RangeIter<'_> {
+        match &self.milliseconds {
+            Some(milliseconds) => milliseconds.range(range),
+            // Only the start of the second, if it is part of the range.
+            None => RangeIter::Range((*range.start()).max(0)..=(*range.end()).min(0)),
+        }
+    }
+
+    /// Returns `true` if the pattern ticks at the given millisecond.
+    fn matches_millisecond(&self, millisecond: i16) -> bool {
+        self.milliseconds
+            .as_ref()
+            .map_or(millisecond == 0, |milliseconds| {
+                milliseconds.contains(millisecond)
+            })
+    }
+
+    /// Returns `true` if days only need to match either the day-of-month or the day-of-week
+    /// field.
+    fn matches_either_day(&self) -> bool {
+        self.day_match == DayMatch::Or && !self.days_is_full() && !self.weekdays_is_full()
+    }
+
+    /// Returns `true` if the day-of-week field matches every day.
+    fn weekdays_is_full(&self) -> bool {
+        if self.weekdays.is_empty() {
+            self.nth_weekdays.is_empty() && !self.weekdays.has_exclusions()
+        } else {
+            self.weekdays.is_full()
+        }
+    }
+
+    /// Returns `true` if the day-of-month field matches every day.
+    fn days_is_full(&self) -> bool {
+        if self.days.is_empty() {
+            self.last_days.is_empty()
+                && self.nearest_weekdays.is_empty()
+                && !self.days.has_exclusions()
+                && !self.last_days.has_exclusions()
+        } else {
+            self.days.is_full() && !self.last_days.has_exclusions()
+        }
+    }
+
+    /// Returns `true` if the given day matches the day-of-month and day-of-week fields.
+    ///
+    /// If `either` is `true`, it is sufficient if the day matches one of the two fields.
+    fn matches_day(&self, year: i16, month: i8, day: i8, either: bool) -> bool {
+        if !self.matches_day_of_year(year, month, day) {
+            return false;
+        }
+
+        let day_matches = self.matches_day_of_month(year, month, day);
+        if day_matches == either {
+            // Either the day already matched in `DayMatch::Or` mode, or it did not match in
+            // `DayMatch::And` mode. The weekday is irrelevant in both cases.
+            return day_matches;
+        }
+
+        if self.weekdays_is_full() {
+            return true;
+        }
+
+        Date::new(year, month, day).is_ok_and(|date| self.matches_day_of_week(date))
+    }
+
+    /// Returns `true` if any day of the given month matches.
+    fn matches_any_day(&self, year: i16, month: i8, either: bool) -> bool {
+        (Days::MIN..=days_in_month(month, year))
+            .any(|day| self.matches_day(year, month, day, either))
+    }
+
+    /// Returns `true` if the given day matches the day-of-year and ISO week fields.
+    fn matches_day_of_year(&self, year: i16, month: i8, day: i8) -> bool {
+        if self.days_of_year.is_empty() && self.iso_weeks.is_empty() {
+            return true;
+        }
+
+        Date::new(year, month, day).is_ok_and(|date| {
+            self.days_of_year.contains(date.day_of_year())
+                && (self.iso_weeks.is_empty()
+                    || self.iso_weeks.contains(date.iso_week_date().week()))
+        })
+    }
+
+    /// Returns `true` if the given date matches the day-of-week field.
+    ///
+    /// Excluded weekdays never match, even if they were selected via nth weekdays.
+    fn matches_day_of_week(&self, date: Date) -> bool {
+        let weekday = date.weekday().to_monday_one_offset();
+
+        if self.weekdays.is_excluded(weekday) {
+            return false;
+        }
+
+        if self.nth_weekdays.is_empty() {
+            return self.weekdays.contains(weekday);
+        }
+
+        self.nth_weekdays.contains(date)
+            || (!self.weekdays.is_empty() && self.weekdays.contains(weekday))
+    }
+
+    /// Returns `true` if the given day matches the day-of-month field.
+    ///
+    /// Excluded days never match, regardless of whether they are counted from the start or the
+    /// end of the month and whether they were selected via days counted from the other end of the
+    /// month or nearest weekdays.
+    fn matches_day_of_month(&self, year: i16, month: i8, day: i8) -> bool {
+        let from_end = days_in_month(month, year) - day + 1;
+        if self.days.is_excluded(day) || self.last_days.is_excluded(from_end) {
+            return false;
+        }
+
+        if self.last_days.is_empty() && self.nearest_weekdays.is_empty() {
+            return self.days.contains(day);
+        }
+
+        (!self.days.is_empty() && self.days.contains(day))
+            || (!self.last_days.is_empty() && self.last_days.contains(from_end))
+            || self.nearest_weekdays.contains(year, month, day)
+    }
+}
+
+impl Pattern for Cron {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let instant = ceil_millisecond(instant.checked_add(1.nanosecond()).ok()?)?;
+        self.next_after_or_current(instant.max(range.start), range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        // The search ignores sub-millisecond precision, so it already starts before an instant
+        // which is not on a millisecond boundary.
+        let instant = instant.min(range.end);
+        let instant = if instant.subsec_nanosecond() % 1_000_000 > 0 {
+            instant
+        } else {
+            instant.checked_sub(1.millisecond()).ok()?
+        };
+        self.previous_before_or_current(instant, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let instant = instant.max(range.start).min(range.end);
+
+        let Some(next) = ceil_millisecond(instant)
+            .and_then(|instant| self.next_after_or_current(instant, range))
+        else {
+            return self.previous_before(instant, range);
+        };
+
+        if next == instant {
+            return Some(next);
+        }
+
+        let Some(previous) = self.previous_before(instant, range) else {
+            return Some(next);
+        };
+
+        if instant.duration_since(previous) >= instant.duration_until(next) {
+            Some(next)
+        } else {
+            Some(previous)
+        }
+    }
+}
+
+impl private::Sealed for Cron {}
+
+/// A set of nth weekday of the month constraints like "second Tuesday" or "last Friday".
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct NthWeekdays {
+    /// Bitmasks of the nth occurrences per weekday, indexed by the zero-based weekday offset
+    /// from Monday. Bits `0..5` represent the first to fifth occurrence from the start of the
+    /// month, bits `5..10` the last to fifth-to-last occurrence.
+    masks: [u16; 7],
+}
+
+impl NthWeekdays {
+    const MAX: i8 = 5;
+
+    fn is_empty(&self) -> bool {
+        self.masks.iter().all(|&mask| mask == 0)
+    }
+
+    fn try_insert(&mut self, nth: i8, weekday: Weekday) -> Result<(), Error> {
+        if nth == 0 {
+            return Err(err!("nth weekday must not be zero"));
+        }
+
+        if !(-Self::MAX..=Self::MAX).contains(&nth) {
+            return Err(Error::range(nth, -Self::MAX, Self::MAX));
+        }
+
+        self.masks[Self::index(weekday)] |= Self::bit(nth);
+        Ok(())
+    }
+
+    fn contains(&self, date: Date) -> bool {
+        let mask = self.masks[Self::index(date.weekday())];
+        if mask == 0 {
+            return false;
+        }
+
+        let nth = (date.day() - 1) / 7 + 1;
+        let nth_from_end = (date.days_in_month() - date.day()) / 7 + 1;
+        mask & (Self::bit(nth) | Self::bit(-nth_from_end)) != 0
+    }
+
+    /// Returns an iterator over all `(nth, weekday)` pairs in the set, where `weekday` is the
+    /// one-based weekday offset from Monday.
+    fn iter(&self) -> impl Iterator<Item = (i8, i8)> + '_ {
+        self.masks.iter().zip(1..).flat_map(|(&mask, weekday)| {
+            (1..=Self::MAX)
+                .chain((-Self::MAX..=-1).rev())
+                .filter(move |&nth| mask & Self::bit(nth) != 0)
+                .map(move |nth| (nth, weekday))
+        })
+    }
+
+    fn index(weekday: Weekday) -> usize {
+        usize::from(weekday.to_monday_zero_offset().unsigned_abs())
+    }
+
+    fn bit(nth: i8) -> u16 {
+        if nth > 0 {
+            1 << (nth - 1)
+        } else {
+            1 << (Self::MAX - nth - 1)
+        }
+    }
+}
+
+/// A set of days whose nearest weekday (Monday to Friday) within the month should match.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct NearestWeekdays {
+    /// Bitmask of days counted from the start of the month, where bit `0` is the 1st.
+    days: u32,
+    /// Bitmask of days counted backwards from the end of the month, where bit `0` is the last
+    /// day.
+    last_days: u32,
+}
+
+impl NearestWeekdays {
+    fn is_empty(self) -> bool {
+        self.days == 0 && self.last_days == 0
+    }
+
+    fn try_insert(&mut self, day: i8) -> Result<(), Error> {
+        if day == 0 {
+            return Err(err!("day must not be zero"));
+        }
+
+        if !(-Days::MAX..=Days::MAX).contains(&day) {
+            return Err(Error::range(day, -Days::MAX, Days::MAX));
+        }
+
+        if day > 0 {
+            self.days |= Self::bit(day);
+        } else {
+            self.last_days |= Self::bit(-day);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` if `day` is the nearest weekday of any day in the set.
+    fn contains(self, year: i16, month: i8, day: i8) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let last = days_in_month(month, year);
+
+        // The nearest weekday is at most two days away from the day it is derived from.
+        ((day - 2).max(1)..=(day + 2).min(last))
+            .filter(|&target| {
+                self.days & Self::bit(target) != 0
+                    || self.last_days & Self::bit(last - target + 1) != 0
+            })
+            .any(|target| nearest_weekday(year, month, target, last) == day)
+    }
+
+    /// Returns an iterator over all days in the set. Days counted from the end of the month are
+    /// negative.
+    fn iter(self) -> impl Iterator<Item = i8> {
+        let days = (Days::MIN..=Days::MAX).filter(move |&day| self.days & Self::bit(day) != 0);
+        let last_days = (Days::MIN..=Days::MAX)
+            .filter(move |&day| self.last_days & Self::bit(day) != 0)
+            .map(|day| -day);
+        days.chain(last_days)
+    }
+
+    fn bit(day: i8) -> u32 {
+        1 << (day - 1)
+    }
+}
+
+/// Returns the weekday (Monday to Friday) nearest to `day` without leaving the month.
+fn nearest_weekday(year: i16, month: i8, day: i8, last: i8) -> i8 {
+    let Ok(date) = Date::new(year, month, day) else {
+        return day;
+    };
+
+    match date.weekday() {
+        Weekday::Saturday if day == 1 => day + 2,
+        Weekday::Saturday => day - 1,
+        Weekday::Sunday if day == last => day - 2,
+        Weekday::Sunday => day + 1,
+        _ => day,
+    }
+}
+
+/// The set of month shapes which contain at least one day matching a `Cron`.
+///
+/// Whether a day matches usually only depends on the day itself, its weekday and the length of
+/// the month, so all months with the same length which start on the same weekday either contain
+/// a matching day or not. Bit `(len - 28) * 7 + weekday` of `ByMonth` represents the shape of
+/// months with `len` days whose first day is `weekday` (zero-based offset from Monday).
+///
+/// Days of the year and ISO weeks additionally depend on the shape of the year, which is
+/// determined by the weekday of January 1st and whether the year and the year before are leap
+/// years. In that case, bit `month - 1` of entry [`MonthShapes::year_index`] of `ByYear`
+/// represents the month in years of that shape.
+#[derive(Debug, Clone, Copy)]
+enum MonthShapes {
+    ByMonth(u32),
+    ByYear([u16; 28]),
+}
+
+impl MonthShapes {
+    const ALL: u32 = (1 << 28) - 1;
+
+    fn new(cron: &Cron) -> MonthShapes {
+        if cron.days_of_year.is_empty() && cron.iso_weeks.is_empty() {
+            Self::by_month(cron)
+        } else {
+            Self::by_year(cron)
+        }
+    }
+
+    fn by_month(cron: &Cron) -> MonthShapes {
+        let either = cron.matches_either_day();
+        let mut shapes = 0;
+        let mut seen = 0;
+
+        // All month shapes occur within 28 consecutive years without a skipped leap year.
+        'outer: for year in 2000..2028 {
+            for month in Months::MIN..=Months::MAX {
+                let Some(bit) = Self::month_bit(year, month) else {
+                    continue;
+                };
+
+                if seen & bit != 0 {
+                    continue;
+                }
+
+                seen |= bit;
+
+                if cron.matches_any_day(year, month, either) {
+                    shapes |= bit;
+                }
+
+                if seen == Self::ALL {
+                    break 'outer;
+                }
+            }
+        }
+
+        MonthShapes::ByMonth(shapes)
+    }
+
+    fn by_year(cron: &Cron) -> MonthShapes {
+        let either = cron.matches_either_day();
+        let mut shapes = [0; 28];
+        let mut seen = 0u32;
+
+        // All year shapes which exist in the proleptic Gregorian calendar occur within 28
+        // consecutive years without a skipped leap year.
+        for year in 2000..2028 {
+            let Some(index) = Self::year_index(year) else {
+                continue;
+            };
+
+            if seen & (1 << index) != 0 {
+                continue;
+            }
+
+            seen |= 1 << index;
+
+            for month in Months::MIN..=Months::MAX {
+                if cron.matches_any_day(year, month, either) {
+                    shapes[index] |= 1 << (month - 1);
+                }
+            }
+        }
+
+        MonthShapes::ByYear(shapes)
+    }
+
+    /// Returns `true` if the given month contains a matching day.
+    fn contains(self, year: i16, month: i8) -> bool {
+        match self {
+            MonthShapes::ByMonth(shapes) => {
+                Self::month_bit(year, month).is_some_and(|bit| shapes & bit != 0)
+            }
+            MonthShapes::ByYear(shapes) => {
+                Self::year_index(year).is_some_and(|index| shapes[index] & (1 << (month - 1)) != 0)
+            }
+        }
+    }
+
+    /// Returns `true` if the given month contains a matching day in any year.
+    fn contains_month(self, month: i8) -> bool {
+        match self {
+            MonthShapes::ByMonth(shapes) => {
+                let contains_len =
+                    |len: i8| shapes & (0b111_1111 << ((len - 28).unsigned_abs() * 7)) != 0;
+
+                // Every month starts on every weekday in some year, so it is sufficient to check
+                // the possible lengths of the month.
+                match month {
+                    2 => contains_len(28) || contains_len(29),
+                    month => contains_len(days_in_month(month, 1)),
+                }
+            }
+            MonthShapes::ByYear(shapes) => {
+                shapes.iter().any(|months| months & (1 << (month - 1)) != 0)
+            }
+        }
+    }
+
+    fn month_bit(year: i16, month: i8) -> Option<u32> {
+        let first = Date::new(year, month, 1).ok()?;
+        let len = (days_in_month(month, year) - 28).unsigned_abs();
+        let weekday = first.weekday().to_monday_zero_offset().unsigned_abs();
+        Some(1 << (len * 7 + weekday))
+    }
+
+    /// Returns the index of the shape of the given year.
+    fn year_index(year: i16) -> Option<usize> {
+        let first = Date::new(year, 1, 1).ok()?;
+        let weekday = usize::from(first.weekday().to_monday_zero_offset().unsigned_abs());
+        let leap = usize::from(is_leap_year(year));
+        let previous_leap = usize::from(is_leap_year(year - 1));
+        Some(weekday * 4 + leap * 2 + previous_leap)
+    }
+}
+
+struct DateTimeClamp {
+    year: i16,
+    month: i8,
+    day: i8,
+    hour: i8,
+    minute: i8,
+    second: i8,
+    millisecond: i16,
+}
+
+impl DateTimeClamp {
+    fn new(date: DateTime) -> DateTimeClamp {
+        DateTimeClamp {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            hour: date.hour(),
+            minute: date.minute(),
+            second: date.second(),
+            millisecond: date.millisecond(),
+        }
+    }
+
+    fn months_to_max(&mut self) {
+        self.month = Months::MAX;
+        self.days_to_max();
+    }
+
+    fn months_to_min(&mut self) {
+        self.month = Months::MIN;
+        self.days_to_min();
+    }
+
+    fn days_to_max(&mut self) {
+        self.day = Days::MAX;
+        self.hours_to_max();
+    }
+
+    fn days_to_min(&mut self) {
+        self.day = Days::MIN;
+        self.hours_to_min();
+    }
+
+    fn hours_to_max(&mut self) {
+        self.hour = Hours::MAX;
+        self.minutes_to_max();
+    }
+
+    fn hours_to_min(&mut self) {
+        self.hour = Hours::MIN;
+        self.minutes_to_min();
+    }
+
+    fn minutes_to_max(&mut self) {
+        self.minute = Minutes::MAX;
+        self.seconds_to_max();
+    }
+
+    fn minutes_to_min(&mut self) {
+        self.minute = Minutes::MIN;
+        self.seconds_to_min();
+    }
+
+    fn seconds_to_max(&mut self) {
+        self.second = Seconds::MAX;
+        self.milliseconds_to_max();
+    }
+
+    fn seconds_to_min(&mut self) {
+        self.second = Seconds::MIN;
+        self.milliseconds_to_min();
+    }
+
+    fn milliseconds_to_max(&mut self) {
+        self.millisecond = Milliseconds::MAX;
+    }
+
+    fn milliseconds_to_min(&mut self) {
+        self.millisecond = Milliseconds::MIN;
+    }
+}
+
+/// Rounds `instant` up to the next millisecond boundary unless it already is on one.
+fn ceil_millisecond(instant: DateTime) -> Option<DateTime> {
+    let remainder = instant.subsec_nanosecond() % 1_000_000;
+    if remainder == 0 {
+        return Some(instant);
+    }
+
+    instant
+        .checked_add((1_000_000 - remainder).nanoseconds())
+        .ok()
+}
+
+/// Checks the `range` and `step` arguments of the stepped range builder methods.
+fn check_step_range<T>(range: &RangeInclusive<T>, step: usize, min: T, max: T) -> Result<(), Error>
+where
+    T: Copy + PartialOrd + Into<i64> + fmt::Display,
+{
+    if step == 0 {
+        return Err(err!("step must be greater than zero"));
+    }
+
+    for &value in [range.start(), range.end()] {
+        if value < min || value > max {
+            return Err(Error::range(value, min, max));
+        }
+    }
+
+    let (start, end) = (range.start(), range.end());
+    if start > end {
+        return Err(err!(
+            "range start {start} must be less than or equal to range end {end}"
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_leap_year(year: i16) -> bool {
+    let by_four = year % 4 == 0;
+    let by_hundred = year % 100 == 0;
+    let by_four_hundred = year % 400 == 0;
+    by_four && ((!by_hundred) || by_four_hundred)
+}
+
+fn days_in_month(month: i8, year: i16) -> i8 {
+    match month {
+        9 | 4 | 6 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
@@ -0,0 +1,600 @@
+//! Parsing of cron expressions into [`Cron`] values.
+
+use super::{Cron, CronField, CronMacro, DayMatch, NearestWeekdays, NthWeekdays};
+use crate::error::{Error, err};
+use crate::pattern::ranged::{Days, Hours, Minutes, Months, Seconds, Weekdays, Years};
+use alloc::vec::Vec;
+use core::str::FromStr;
+use jiff::civil::Weekday;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+impl Cron {
+    /// Parses a cron expression into a `Cron`.
+    ///
+    /// The expression must consist of 5, 6 or 7 whitespace-separated fields:
+    ///
+    /// | Fields | Layout                                                       |
+    /// |--------|--------------------------------------------------------------|
+    /// | 5      | `minute hour day-of-month month day-of-week`                 |
+    /// | 6      | `second minute hour day-of-month month day-of-week`          |
+    /// | 7      | `second minute hour day-of-month month day-of-week year`     |
+    ///
+    /// Five-field expressions behave like standard cron and only fire at second `0`.
+    ///
+    /// Each field is a comma-separated list of items. An item can be any of the following:
+    ///
+    /// - `*`: every value of the field.
+    /// - `a`: a single value.
+    /// - `a-b`: every value from `a` to `b` (inclusive).
+    /// - `*/n`: every `n`th value of the field starting from its minimum.
+    /// - `a/n`: every `n`th value from `a` to the field maximum.
+    /// - `a-b/n`: every `n`th value from `a` to `b` (inclusive).
+    ///
+    /// Months can also be specified by their case-insensitive three-letter names (`JAN` to
+    /// `DEC`), weekdays by theirs (`SUN` to `SAT`). Like in standard cron, the day-of-week values
+    /// `0` and `7` both refer to Sunday.
+    ///
+    /// The day-of-month field additionally accepts `L` for the last day of the month and `L-n` for
+    /// `n` days before the last day of the month. A `W` suffix selects the weekday (Monday to
+    /// Friday) nearest to the given day within the same month, e.g. `15W` or `LW` for the last
+    /// weekday of the month (see [`Cron::nearest_weekday`]). The day-of-week field accepts `d#n` for the
+    /// `n`th weekday `d` of the month (e.g. `MON#2` or `1#2` for the second Monday) and `dL` for
+    /// the last weekday `d` of the month (e.g. `FRIL` or `5L` for the last Friday). Negative values
+    /// of `n` count backwards from the end of the month.
+    ///
+    /// Like in Vixie cron, a day only needs to match one of the day-of-month and the day-of-week
+    /// fields if neither of them starts with `*`, and the parsed `Cron` uses [`DayMatch::Or`].
+    /// Otherwise, a day needs to match both fields and the parsed `Cron` uses [`DayMatch::And`].
+    /// For example, `0 0 1-31 * MON` fires every day, while `0 0 */2 * MON` only fires on Mondays
+    /// which are odd days of the month.
+    ///
+    /// Like in Jenkins, an item can also be a hashed value `H`, `H(a-b)`, `H/n` or `H(a-b)/n`,
+    /// which resolves to values derived from a seed. Use [`Cron::parse_with_seed`] to provide
+    /// the seed. See [`Cron::hashed`] for details. Without an explicit range, hashed days of the
+    /// month are chosen from `1` to `28`.
+    ///
+    /// Instead of fields, the expression can also be one of the predefined macros `@yearly`,
+    /// `@annually`, `@monthly`, `@weekly`, `@daily`, `@midnight` or `@hourly` (see
+    /// [`CronMacro`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression does not have a valid number of fields or if any of the
+    /// fields is malformed or contains a value that is out of bounds. The error reports the
+    /// offending field together with the position of the invalid item within `expr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Event, ToSeries, pattern::Cron};
+    ///
+    /// // At 09:30 on every weekday in January and July.
+    /// let pattern = Cron::parse("30 9 * JAN,JUL MON-FRI")?;
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(9, 30, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 2).at(9, 30, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 3).at(9, 30, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 6).at(9, 30, 0, 0))));
+    ///
+    /// assert!(Cron::parse("61 * * * *").is_err());
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn parse(expr: &str) -> Result<Cron, Error> {
+        Cron::parse_with(Cron::new(), expr)
+    }
+
+    /// Parses a cron expression into a `Cron`, resolving hashed values using `seed`.
+    ///
+    /// See [`Cron::parse`] for the syntax of cron expressions and [`Cron::seed`] for details about
+    /// seeds.
+    ///
+    /// Macros like `@daily` don't contain hashed values, so the seed does not affect their
+    /// schedule. Like for all other expressions, it is kept on the returned `Cron` and applies to
+    /// hashed fields configured afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression is invalid. See [`Cron::parse`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::Cron;
+    ///
+    /// let a = Cron::parse_with_seed("H H(0-5) * * *", "backup-1")?;
+    /// let b = Cron::parse_with_seed("H H(0-5) * * *", "backup-1")?;
+    /// assert_eq!(a, b);
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn parse_with_seed(expr: &str, seed: &str) -> Result<Cron, Error> {
+        Cron::parse_with(Cron::new().seed(seed), expr)
+    }
+
+    fn parse_with(cron: Cron, expr: &str) -> Result<Cron, Error> {
+        let trimmed = expr.trim();
+        if trimmed.starts_with('@') {
+            return trimmed.parse::<CronMacro>().map(|macro_| Cron {
+                seed: cron.seed,
+                ..Cron::from(macro_)
+            });
+        }
+
+        let fields = split_whitespace(expr);
+
+        let (layout, cron): (&[Field], Cron) = match fields.len() {
+            5 => (&Field::LAYOUT[1..6], cron.second(0)),
+            6 => (&Field::LAYOUT[..6], cron),
+            7 => (&Field::LAYOUT, cron),
+            n => return Err(err!("cron expression must have 5 to 7 fields but got {n}")),
+        };
+
+        // Like in Vixie cron, the day fields are only combined with `DayMatch::Or` if neither of
+        // them starts with `*`.
+        let mut star = false;
+
+        let cron =
+            layout
+                .iter()
+                .zip(fields)
+                .try_fold(cron, |cron, (&field, (offset, value))| {
+                    if matches!(field, Field::Day | Field::Weekday) {
+                        star |= value.starts_with('*');
+                    }
+
+                    field.parse(cron, expr, offset, value)
+                })?;
+
+        if star {
+            return Ok(cron.day_match(DayMatch::And));
+        }
+
+        // A `Cron` only combines restricted day fields. If one of them matches every day, so does
+        // their union, which is expressed by clearing the other field.
+        let cron = if cron.days_is_full() {
+            Cron {
+                weekdays: Weekdays::default(),
+                nth_weekdays: NthWeekdays::default(),
+                ..cron
+            }
+        } else if cron.weekdays_is_full() {
+            Cron {
+                days: Days::default(),
+                last_days: Days::default(),
+                nearest_weekdays: NearestWeekdays::default(),
+                ..cron
+            }
+        } else {
+            cron
+        };
+
+        Ok(cron.day_match(DayMatch::Or))
+    }
+}
+
+impl Cron {
+    /// Parses a [Quartz] cron expression into a `Cron`.
+    ///
+    /// Quartz expressions consist of 6 or 7 whitespace-separated fields:
+    ///
+    /// | Fields | Layout                                                       |
+    /// |--------|--------------------------------------------------------------|
+    /// | 6      | `second minute hour day-of-month month day-of-week`          |
+    /// | 7      | `second minute hour day-of-month month day-of-week year`     |
+    ///
+    /// The syntax of the individual fields is the same as described in [`Cron::parse`] with the
+    /// following differences:
+    ///
+    /// - Exactly one of the day-of-month and the day-of-week fields must be `?`, which leaves that
+    ///   field unrestricted.
+    /// - Weekdays are numbered from `1` (Sunday) to `7` (Saturday). Named weekdays (`SUN` to
+    ///   `SAT`) work like in standard cron.
+    /// - A plain `L` in the day-of-week field means Saturday, the last day of the week.
+    ///
+    /// [Quartz]: https://www.quartz-scheduler.org/documentation/quartz-2.3.0/tutorials/crontrigger.html
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression does not have a valid number of fields, if not exactly
+    /// one of the day fields is `?` or if any of the fields is malformed or contains a value that
+    /// is out of bounds. The error reports the offending field together with the position of the
+    /// invalid item within `expr`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Event, ToSeries, pattern::Cron};
+    ///
+    /// // At 10:15 on the last Friday of every month from 2025 to 2030.
+    /// let pattern = Cron::parse_quartz("0 15 10 ? * 6L 2025-2030")?;
+    ///
+    /// let series = date(2025, 1, 1).to_series(pattern)?;
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 31).at(10, 15, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 2, 28).at(10, 15, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 3, 28).at(10, 15, 0, 0))));
+    ///
+    /// assert!(Cron::parse_quartz("0 15 10 * * 6L").is_err());
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn parse_quartz(expr: &str) -> Result<Cron, Error> {
+        let fields = split_whitespace(expr);
+
+        let n = fields.len();
+        if !(6..=7).contains(&n) {
+            return Err(err!(
+                "quartz cron expression must have 6 or 7 fields but got {n}"
+            ));
+        }
+
+        if (fields[3].1 == "?") == (fields[5].1 == "?") {
+            return Err(err!(
+                "exactly one of the day-of-month and day-of-week fields must be \"?\""
+            ));
+        }
+
+        Field::QUARTZ_LAYOUT.iter().zip(fields).try_fold(
+            Cron::new(),
+            |cron, (field, (offset, value))| {
+                if value == "?" && matches!(field, Field::Day | Field::QuartzWeekday) {
+                    return Ok(cron);
+                }
+
+                field.parse(cron, expr, offset, value)
+            },
+        )
+    }
+}
+
+impl FromStr for Cron {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cron::parse(s)
+    }
+}
+
+/// The fields of a cron expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Weekday,
+    /// The day-of-week field of Quartz expressions, which numbers weekdays from `1` (Sunday) to
+    /// `7` (Saturday).
+    QuartzWeekday,
+    Year,
+}
+
+impl Field {
+    /// All fields in the order of the 7-field cron layout.
+    const LAYOUT: [Field; 7] = [
+        Field::Second,
+        Field::Minute,
+        Field::Hour,
+        Field::Day,
+        Field::Month,
+        Field::Weekday,
+        Field::Year,
+    ];
+
+    /// All fields in the order of the 7-field Quartz layout.
+    const QUARTZ_LAYOUT: [Field; 7] = [
+        Field::Second,
+        Field::Minute,
+        Field::Hour,
+        Field::Day,
+        Field::Month,
+        Field::QuartzWeekday,
+        Field::Year,
+    ];
+
+    fn what(self) -> &'static str {
+        match self {
+            Field::Second => "second field",
+            Field::Minute => "minute field",
+            Field::Hour => "hour field",
+            Field::Day => "day-of-month field",
+            Field::Month => "month field",
+            Field::Weekday | Field::QuartzWeekday => "day-of-week field",
+            Field::Year => "year field",
+        }
+    }
+
+    fn bounds(self) -> (i16, i16) {
+        match self {
+            Field::Second => (Seconds::MIN.into(), Seconds::MAX.into()),
+            Field::Minute => (Minutes::MIN.into(), Minutes::MAX.into()),
+            Field::Hour => (Hours::MIN.into(), Hours::MAX.into()),
+            Field::Day => (Days::MIN.into(), Days::MAX.into()),
+            Field::Month => (Months::MIN.into(), Months::MAX.into()),
+            // Sunday is `0` in cron but `7` in `Weekdays`.
+            Field::Weekday => (0, Weekdays::MAX.into()),
+            Field::QuartzWeekday => (Weekdays::MIN.into(), Weekdays::MAX.into()),
+            Field::Year => (Years::MIN, Years::MAX),
+        }
+    }
+
+    /// Looks up a named value, e.g. `JAN` for months or `MON` for weekdays.
+    fn named(self, name: &str) -> Option<i16> {
+        let (names, offset): (&[&str], usize) = match self {
+            Field::Month => (&MONTH_NAMES, 1),
+            Field::Weekday => (&WEEKDAY_NAMES, 0),
+            Field::QuartzWeekday => (&WEEKDAY_NAMES, 1),
+            _ => return None,
+        };
+
+        names
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(name))
+            .and_then(|index| i16::try_from(index + offset).ok())
+    }
+
+    /// Parses a comma-separated list of items and applies their values to `cron`.
+    ///
+    /// `offset` is the byte offset of `value` within `expr` and only used for error reporting.
+    fn parse(self, mut cron: Cron, expr: &str, offset: usize, value: &str) -> Result<Cron, Error> {
+        // A plain `*` leaves the field unrestricted.
+        if value == "*" {
+            return Ok(cron);
+        }
+
+        let mut item_offset = offset;
+
+        for item in value.split(',') {
+            cron = self
+                .parse_item(cron, item)
+                .map_err(|err| Error::parse(self.what(), position(expr, item_offset), err))?;
+            item_offset += item.len() + 1;
+        }
+
+        Ok(cron)
+    }
+
+    fn parse_item(self, cron: Cron, item: &str) -> Result<Cron, Error> {
+        if let Some(hashed) = item.strip_prefix('H') {
+            return self.parse_hashed(cron, hashed);
+        }
+
+        if self == Field::Day
+            && let Some(day) = item.strip_suffix('W')
+        {
+            let day = match parse_last_day(day)? {
+                Some(from_end) => -from_end - 1,
+                None => to_i8(self.value(day)?)?,
+            };
+            return cron.try_nearest_weekday(day);
+        }
+
+        if self == Field::Day
+            && let Some(from_end) = parse_last_day(item)?
+        {
+            return cron.try_day(-from_end - 1);
+        }
+
+        if self == Field::QuartzWeekday && item == "L" {
+            return Ok(cron.weekday(Weekday::Saturday));
+        }
+
+        if matches!(self, Field::Weekday | Field::QuartzWeekday)
+            && let Some((nth, weekday)) = self.parse_nth_weekday(item)?
+        {
+            return cron.try_nth_weekday(nth, weekday);
+        }
+
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, Some(parse_step(step)?)),
+            None => (item, None),
+        };
+
+        let (min, max) = self.bounds();
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (self.value(start)?, self.range_end(end)?)
+        } else {
+            let value = self.value(range)?;
+            (value, if step.is_some() { max } else { value })
+        };
+
+        if start > end {
+            return Err(err!(
+                "range start {start} must be less than or equal to range end {end}"
+            ));
+        }
+
+        (start..=end)
+            .step_by(step.unwrap_or(1))
+            .try_fold(cron, |cron, value| self.insert(cron, value))
+    }
+
+    fn value(self, value: &str) -> Result<i16, Error> {
+        let (min, max) = self.bounds();
+
+        let value = match self.named(value) {
+            Some(value) => value,
+            None => value
+                .parse::<i16>()
+                .map_err(|_| err!("invalid value \"{value}\""))?,
+        };
+
+        if (min..=max).contains(&value) {
+            Ok(value)
+        } else {
+            Err(Error::range(value, min, max))
+        }
+    }
+
+    /// Parses the part after the `H` of hashed items like `H`, `H(a-b)`, `H/n` and `H(a-b)/n`.
+    fn parse_hashed(self, cron: Cron, hashed: &str) -> Result<Cron, Error> {
+        let field = self
+            .hashed_field()
+            .ok_or_else(|| err!("hashed values are not supported"))?;
+
+        let (range, step) = match hashed.split_once('/') {
+            Some((range, step)) => (range, Some(parse_step(step)?)),
+            None => (hashed, None),
+        };
+
+        let (start, end) = if range.is_empty() {
+            let (start, end) = field.hashed_bounds().into_inner();
+            (start.into(), end.into())
+        } else {
+            let (start, end) = range
+                .strip_prefix('(')
+                .and_then(|range| range.strip_suffix(')'))
+                .and_then(|range| range.split_once('-'))
+                .ok_or_else(|| err!("invalid value \"H{hashed}\""))?;
+            (self.value(start)?, self.range_end(end)?)
+        };
+
+        if start > end {
+            return Err(err!(
+                "range start {start} must be less than or equal to range end {end}"
+            ));
+        }
+
+        field
+            .hashed_values(cron.seed, start, end, step)
+            .try_fold(cron, |cron, value| self.insert(cron, value))
+    }
+
+    fn hashed_field(self) -> Option<CronField> {
+        match self {
+            Field::Second => Some(CronField::Second),
+            Field::Minute => Some(CronField::Minute),
+            Field::Hour => Some(CronField::Hour),
+            Field::Day => Some(CronField::Day),
+            Field::Month => Some(CronField::Month),
+            Field::Weekday | Field::QuartzWeekday => Some(CronField::Weekday),
+            Field::Year => None,
+        }
+    }
+
+    /// Parses `d#n` and `dL` day-of-week items into the nth occurrence of a weekday.
+    fn parse_nth_weekday(self, item: &str) -> Result<Option<(i8, Weekday)>, Error> {
+        let (weekday, nth) = if let Some((weekday, nth)) = item.split_once('#') {
+            let nth = nth
+                .parse::<i8>()
+                .map_err(|_| err!("invalid value \"{item}\""))?;
+            (weekday, nth)
+        } else if let Some(weekday) = item.strip_suffix('L').filter(|w| !w.is_empty()) {
+            (weekday, -1)
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some((nth, self.weekday(self.value(weekday)?)?)))
+    }
+
+    /// Converts a day-of-week value into a `Weekday`.
+    fn weekday(self, value: i16) -> Result<Weekday, Error> {
+        let value = to_i8(value)?;
+
+        let weekday = if self == Field::QuartzWeekday {
+            Weekday::from_sunday_one_offset(value)?
+        } else {
+            Weekday::from_sunday_zero_offset(value % 7)?
+        };
+
+        Ok(weekday)
+    }
+
+    fn range_end(self, value: &str) -> Result<i16, Error> {
+        match self.value(value)? {
+            // Allow ranges like `FRI-SUN` by treating a Sunday at the range end as `7`.
+            0 if self == Field::Weekday => Ok(7),
+            value => Ok(value),
+        }
+    }
+
+    fn insert(self, cron: Cron, value: i16) -> Result<Cron, Error> {
+        match self {
+            Field::Second => cron.try_second(to_i8(value)?),
+            Field::Minute => cron.try_minute(to_i8(value)?),
+            Field::Hour => cron.try_hour(to_i8(value)?),
+            Field::Day => cron.try_day(to_i8(value)?),
+            Field::Month => cron.try_month(to_i8(value)?),
+            Field::Weekday | Field::QuartzWeekday => Ok(cron.weekday(self.weekday(value)?)),
+            Field::Year => cron.try_year(value),
+        }
+    }
+}
+
+fn to_i8(value: i16) -> Result<i8, Error> {
+    i8::try_from(value).map_err(|_| Error::range(value, i8::MIN, i8::MAX))
+}
+
+/// Parses `L` and `L-n` day-of-month items into the number of days before the last day.
+fn parse_last_day(item: &str) -> Result<Option<i8>, Error> {
+    let Some(rest) = item.strip_prefix('L') else {
+        return Ok(None);
+    };
+
+    if rest.is_empty() {
+        return Ok(Some(0));
+    }
+
+    let offset = rest
+        .strip_prefix('-')
+        .and_then(|offset| offset.parse::<i8>().ok())
+        .ok_or_else(|| err!("invalid value \"{item}\""))?;
+
+    if (0..Days::MAX).contains(&offset) {
+        Ok(Some(offset))
+    } else {
+        Err(Error::range(offset, 0, Days::MAX - 1))
+    }
+}
+
+fn parse_step(step: &str) -> Result<usize, Error> {
+    match step.parse::<usize>() {
+        Ok(0) => Err(err!("step must be greater than zero")),
+        Ok(step) => Ok(step),
+        Err(_) => Err(err!("invalid step \"{step}\"")),
+    }
+}
+
+/// Splits `s` at whitespace and returns the non-empty parts together with their byte offsets.
+fn split_whitespace(s: &str) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut start = None;
+
+    for (index, c) in s.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(offset)) => {
+                parts.push((offset, &s[offset..index]));
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+
+    if let Some(offset) = start {
+        parts.push((offset, &s[offset..]));
+    }
+
+    parts
+}
+
+/// Converts a byte offset within `s` into a 1-based character position.
+fn position(s: &str, offset: usize) -> usize {
+    s[..offset].chars().count() + 1
+}
@@ -0,0 +1,291 @@
+use crate::error::{Error, err};
+use crate::{DateTimeRange, Pattern, private};
+use jiff::civil::DateTime;
+
+/// A trait for user-defined recurrence patterns.
+///
+/// The [`Pattern`] trait is sealed, so patterns defined outside of this crate implement
+/// `CustomPattern` instead and are wrapped in a [`Custom`] to be used with
+/// [`Series`][crate::Series], [`Combine`][crate::Combine] and everything else accepting a
+/// `Pattern`.
+///
+/// # Invariants
+///
+/// All methods receive the `range` of the series, which contains all datetimes from
+/// [`range.start()`][DateTimeRange::start] (inclusive) to [`range.end()`][DateTimeRange::end]
+/// (exclusive). Patterns which are relative to some point in time should use
+/// [`range.fixpoint()`][DateTimeRange::fixpoint] as their starting point.
+///
+/// Implementations must uphold the following invariants for any `instant`, including instants
+/// outside of `range`:
+///
+/// - Every returned datetime is within `range`.
+/// - [`next_after`][CustomPattern::next_after] returns the earliest event strictly after
+///   `instant`, or `None` if there is none. If `instant` is before the range start, this is the
+///   first event of the range.
+/// - [`previous_before`][CustomPattern::previous_before] returns the latest event strictly
+///   before `instant`, or `None` if there is none. If `instant` is at or after the range end, this
+///   is the last event of the range.
+/// - [`closest_to`][CustomPattern::closest_to] returns an event which is at least as close to
+///   `instant` as the events returned by the other two methods. It may return an event at
+///   `instant` and only returns `None` if the range contains no events.
+///
+/// Use [`check_pattern`] to test implementations against these invariants.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{ToSpan, civil::{DateTime, date}};
+/// use recurring::{DateTimeRange, Series};
+/// use recurring::pattern::{CustomPattern, check_pattern, custom};
+///
+/// /// Ticks at the start of every hour, i.e. like `hourly(1)` with a fixpoint at midnight.
+/// #[derive(Clone)]
+/// struct Hourly;
+///
+/// impl Hourly {
+///     fn floor(instant: DateTime) -> DateTime {
+///         instant.date().at(instant.hour(), 0, 0, 0)
+///     }
+/// }
+///
+/// impl CustomPattern for Hourly {
+///     fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+///         let instant = instant.max(range.start() - 1.nanosecond());
+///         let next = Self::floor(instant).checked_add(1.hour()).ok()?;
+///         (next < range.end()).then_some(next)
+///     }
+///
+///     fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+///         let instant = instant.min(range.end());
+///         let previous = Self::floor(instant.checked_sub(1.nanosecond()).ok()?);
+///         (previous >= range.start()).then_some(previous)
+///     }
+///
+///     fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+///         let next = self.next_after(instant, range);
+///         let previous = self.previous_before(instant, range);
+///         match (previous, next) {
+///             (Some(previous), Some(next))
+///                 if instant.duration_since(previous) < next.duration_since(instant) =>
+///             {
+///                 Some(previous)
+///             }
+///             (_, Some(next)) => Some(next),
+///             (previous, None) => previous,
+///         }
+///     }
+/// }
+///
+/// let start = date(2025, 1, 1).at(0, 30, 0, 0);
+/// let series = Series::new(start.., custom(Hourly));
+/// assert_eq!(series.first().unwrap().start(), date(2025, 1, 1).at(1, 0, 0, 0));
+///
+/// // Check the invariants at every 17 minutes within and around the range.
+/// let range = DateTimeRange::from(start..start + 1.day());
+/// let instants = (-10..100).map(|i| start + (i * 17).minutes());
+/// check_pattern(&custom(Hourly), range, instants)?;
+/// # Ok::<(), Box<dyn core::error::Error>>(())
+/// ```
+pub trait CustomPattern: Clone {
+    /// Find the next `DateTime` after `instant` within a range.
+    ///
+    /// See the [trait documentation][CustomPattern#invariants] for the invariants.
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime>;
+
+    /// Find the previous `DateTime` before `instant` within a range.
+    ///
+    /// See the [trait documentation][CustomPattern#invariants] for the invariants.
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime>;
+
+    /// Find a `DateTime` closest to `instant` within a range.
+    ///
+    /// See the [trait documentation][CustomPattern#invariants] for the invariants.
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime>;
+}
+
+/// An adapter which turns a [`CustomPattern`] into a [`Pattern`].
+///
+/// Values of this type are usually created via [`custom()`][crate::pattern::custom()].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Custom<T>(T);
+
+impl<T: CustomPattern> Custom<T> {
+    /// Create a new `Custom` from a user-defined recurrence pattern.
+    pub fn new(pattern: T) -> Custom<T> {
+        Custom(pattern)
+    }
+
+    /// Returns a reference to the wrapped pattern.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the wrapped pattern.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: CustomPattern> Pattern for Custom<T> {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0.next_after(instant, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0.previous_before(instant, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0.closest_to(instant, range)
+    }
+}
+
+impl<T> private::Sealed for Custom<T> {}
+
+/// Checks that a pattern upholds the invariants documented on [`CustomPattern`].
+///
+/// The invariants are checked at each of the given `instants` within `range`. Use a mix of
+/// instants before, within and after the range, and instants exactly at and in between events
+/// of the pattern.
+///
+/// This works with all [`Pattern`]s and is mainly meant to be used in tests of
+/// [`CustomPattern`] implementations.
+///
+/// # Errors
+///
+/// Returns an error describing the first violated invariant.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{ToSpan, civil::date};
+/// use recurring::DateTimeRange;
+/// use recurring::pattern::{check_pattern, hourly};
+///
+/// let start = date(2025, 1, 1).at(0, 0, 0, 0);
+/// let range = DateTimeRange::from(start..start + 1.day());
+/// let instants = (-10..40).map(|minutes| start + (minutes * 37).minutes());
+///
+/// check_pattern(&hourly(1), range, instants)?;
+/// # Ok::<(), Box<dyn core::error::Error>>(())
+/// ```
+pub fn check_pattern<P, I>(pattern: &P, range: DateTimeRange, instants: I) -> Result<(), Error>
+where
+    P: Pattern,
+    I: IntoIterator<Item = DateTime>,
+{
+    for instant in instants {
+        check_instant(pattern, range, instant)?;
+    }
+
+    Ok(())
+}
+
+/// Checks the invariants of all `Pattern` methods at `instant`.
+fn check_instant<P: Pattern>(
+    pattern: &P,
+    range: DateTimeRange,
+    instant: DateTime,
+) -> Result<(), Error> {
+    let in_range = |date: DateTime| range.start <= date && date < range.end;
+
+    let next = pattern.next_after(instant, range);
+    if let Some(next) = next {
+        if next <= instant || !in_range(next) {
+            return Err(err!(
+                "next_after({instant}) returned {next} which is not after the instant within \
+                 the range {}..{}",
+                range.start,
+                range.end
+            ));
+        }
+
+        // There must not be another event between the instant and the next event.
+        if let Some(previous) = pattern.previous_before(next, range)
+            && previous > instant
+        {
+            return Err(err!(
+                "next_after({instant}) returned {next} but previous_before({next}) returned \
+                 {previous} which is in between"
+            ));
+        }
+    }
+
+    // There must not be an event after the instant if there is no next event.
+    if next.is_none()
+        && let Some(last) = pattern.previous_before(range.end, range)
+        && last > instant
+    {
+        return Err(err!(
+            "next_after({instant}) returned no event but {last} is after the instant"
+        ));
+    }
+
+    let previous = pattern.previous_before(instant, range);
+    if previous.is_none()
+        && let Some(first) = pattern.closest_to(range.start, range)
+        && first < instant
+    {
+        return Err(err!(
+            "previous_before({instant}) returned no event but {first} is before the instant"
+        ));
+    }
+
+    if let Some(previous) = previous {
+        if previous >= instant || !in_range(previous) {
+            return Err(err!(
+                "previous_before({instant}) returned {previous} which is not before the instant \
+                 within the range {}..{}",
+                range.start,
+                range.end
+            ));
+        }
+
+        if let Some(next) = pattern.next_after(previous, range)
+            && next < instant
+        {
+            return Err(err!(
+                "previous_before({instant}) returned {previous} but next_after({previous}) \
+                 returned {next} which is in between"
+            ));
+        }
+    }
+
+    let Some(closest) = pattern.closest_to(instant, range) else {
+        if next.is_some() || previous.is_some() {
+            return Err(err!(
+                "closest_to({instant}) returned no event but the range contains events"
+            ));
+        }
+
+        return Ok(());
+    };
+
+    if !in_range(closest) {
+        return Err(err!(
+            "closest_to({instant}) returned {closest} which is not within the range {}..{}",
+            range.start,
+            range.end
+        ));
+    }
+
+    let distance = |date: DateTime| instant.duration_since(date).abs();
+
+    for other in [next, previous].into_iter().flatten() {
+        if distance(closest) > distance(other) {
+            return Err(err!(
+                "closest_to({instant}) returned {closest} but {other} is closer"
+            ));
+        }
+    }
+
+    if closest != instant && next.is_none() && previous.is_none() {
+        return Err(err!(
+            "closest_to({instant}) returned {closest} but next_after and previous_before \
+             returned no events"
+        ));
+    }
+
+    Ok(())
+}
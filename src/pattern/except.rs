@@ -0,0 +1,197 @@
+use crate::error::{Error, err};
+use crate::pattern::utils::{closest_to, next_at_or_after, pick_best};
+use crate::{DateTimeRange, Pattern, private};
+use jiff::civil::DateTime;
+
+/// A recurrence pattern with the events of another pattern removed.
+///
+/// This type is returned by the `.except()` method of the [`Combine`][crate::Combine] trait. It
+/// produces all events of the `pattern` which are not also produced by the `exclusion`, similar to
+/// the `EXRULE` and `EXDATE` properties of RFC 5545. Use an
+/// [`Explicit`][crate::pattern::Explicit] pattern as the exclusion to remove a list of datetimes.
+///
+/// Searching for an event skips over excluded events of the `pattern` until an event which is not
+/// excluded or the end of the series range is found. To prevent an exclusion which removes all or
+/// almost all events from searching (nearly) forever, every search gives up after checking a
+/// limited number of events and returns no event. This budget defaults to
+/// [`Except::DEFAULT_BUDGET`] and can be changed via [`.budget()`][Except::budget].
+///
+/// Since exclusions are applied by the pattern itself, all methods of a
+/// [`Series`][crate::Series] like [`range`][crate::Series::range] and
+/// [`split_off`][crate::Series::split_off] respect them.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::{Weekday, date};
+/// use recurring::{Combine, Event, Series, pattern::cron};
+///
+/// let weekdays_at_nine = cron()
+///     .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+///     .hour(9).minute(0).second(0);
+/// let new_year = cron().month(1).day(1);
+///
+/// let series = Series::new(date(2025, 1, 1).at(0, 0, 0, 0).., weekdays_at_nine.except(new_year));
+///
+/// let mut events = series.iter();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 2).at(9, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 3).at(9, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 6).at(9, 0, 0, 0))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Except<P, X> {
+    pattern: P,
+    exclusion: X,
+    budget: usize,
+}
+
+impl<P, X> Except<P, X>
+where
+    P: Pattern,
+    X: Pattern,
+{
+    /// The default number of events checked by a single search.
+    pub const DEFAULT_BUDGET: usize = 10_000;
+
+    /// Create a new `Except` which removes the events of `exclusion` from `pattern`.
+    ///
+    /// Consider using the [`.except()`][crate::Combine::except] method of the `Combine` trait
+    /// instead because it's more convenient.
+    pub fn new(pattern: P, exclusion: X) -> Except<P, X> {
+        Except {
+            pattern,
+            exclusion,
+            budget: Self::DEFAULT_BUDGET,
+        }
+    }
+
+    /// Set the maximum number of events of the pattern checked by a single search.
+    ///
+    /// If a search does not find an event which is not excluded within the budget, it gives up and
+    /// returns no event. This effectively ends the series at that point.
+    ///
+    /// The fallible version of this method is [`Except::try_budget`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::{Combine, pattern::{cron, hourly}};
+    ///
+    /// let pattern = hourly(1).except(cron().hours(0..=22)).budget(24);
+    /// ```
+    #[must_use]
+    pub fn budget(self, budget: usize) -> Except<P, X> {
+        self.try_budget(budget).expect("invalid search budget")
+    }
+
+    /// Set the maximum number of events of the pattern checked by a single search.
+    ///
+    /// See [`.budget()`][Except::budget] for details.
+    ///
+    /// The panicking version of this method is [`Except::budget`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `budget` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::{Combine, pattern::{cron, hourly}};
+    ///
+    /// let pattern = hourly(1).except(cron().hours(0..=22)).try_budget(24)?;
+    ///
+    /// assert!(pattern.try_budget(0).is_err());
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn try_budget(mut self, budget: usize) -> Result<Except<P, X>, Error> {
+        if budget == 0 {
+            return Err(err!("search budget must be positive"));
+        }
+
+        self.budget = budget;
+        Ok(self)
+    }
+
+    /// Returns the pattern and the exclusion.
+    pub fn into_parts(self) -> (P, X) {
+        (self.pattern, self.exclusion)
+    }
+
+    /// Returns the maximum number of events checked by a single search.
+    pub(crate) fn search_budget(&self) -> usize {
+        self.budget
+    }
+
+    fn is_excluded(&self, instant: DateTime, range: DateTimeRange) -> bool {
+        next_at_or_after(&self.exclusion, instant, range) == Some(instant)
+    }
+
+    /// Skips forward from `candidate`, which is an event of `pattern`, until it is not excluded.
+    fn skip_forward(&self, mut candidate: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        for _ in 0..self.budget {
+            if !self.is_excluded(candidate, range) {
+                return Some(candidate);
+            }
+
+            candidate = self.pattern.next_after(candidate, range)?;
+        }
+
+        None
+    }
+
+    /// Skips backward from `candidate`, which is an event of `pattern`, until it is not excluded.
+    fn skip_backward(&self, mut candidate: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        for _ in 0..self.budget {
+            if !self.is_excluded(candidate, range) {
+                return Some(candidate);
+            }
+
+            candidate = self.pattern.previous_before(candidate, range)?;
+        }
+
+        None
+    }
+}
+
+impl<P, X> Pattern for Except<P, X>
+where
+    P: Pattern,
+    X: Pattern,
+{
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let candidate = self.pattern.next_after(instant, range)?;
+        self.skip_forward(candidate, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let candidate = self.pattern.previous_before(instant, range)?;
+        self.skip_backward(candidate, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let next = next_at_or_after(&self.pattern, instant, range)
+            .and_then(|candidate| self.skip_forward(candidate, range));
+        let previous = self.previous_before(instant, range);
+        pick_best(previous, next, |previous, next| {
+            closest_to(instant, previous, next)
+        })
+    }
+}
+
+impl<P, X> Default for Except<P, X>
+where
+    P: Pattern + Default,
+    X: Pattern + Default,
+{
+    fn default() -> Self {
+        Except::new(P::default(), X::default())
+    }
+}
+
+impl<P, X> private::Sealed for Except<P, X> {}
@@ -0,0 +1,133 @@
+use crate::pattern::utils::{closest_to, pick_best};
+use crate::{DateTimeRange, Pattern, private};
+use alloc::vec::Vec;
+use jiff::civil::DateTime;
+
+/// A recurrence pattern for events at an explicit list of datetimes.
+///
+/// The datetimes are kept sorted and deduplicated, and lookups use binary search. Combine this
+/// with other patterns via [`.and()`][crate::Combine::and] to add extra events, similar to the
+/// `RDATE` property of RFC 5545, or via [`.except()`][crate::Combine::except] to remove events,
+/// similar to `EXDATE`.
+///
+/// Unlike interval-based patterns, the datetimes are absolute and do not depend on the fixpoint of
+/// the series.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::{Weekday, date};
+/// use recurring::{Combine, Event, Series, pattern::{cron, explicit}};
+///
+/// let first_monday_of_quarter = cron()
+///     .months([1, 4, 7, 10])
+///     .nth_weekday(1, Weekday::Monday)
+///     .hour(10).minute(0).second(0);
+/// let extra_meetings = explicit([date(2025, 2, 17).at(14, 0, 0, 0)]);
+///
+/// let pattern = first_monday_of_quarter.and(extra_meetings);
+/// let series = Series::new(date(2025, 1, 1).at(0, 0, 0, 0).., pattern);
+///
+/// let mut events = series.iter();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 6).at(10, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 2, 17).at(14, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 4, 7).at(10, 0, 0, 0))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Explicit {
+    dates: Vec<DateTime>,
+}
+
+impl Explicit {
+    /// Create a new `Explicit` from a list of datetimes.
+    ///
+    /// The datetimes may be given in any order and may contain duplicates.
+    ///
+    /// Consider using the [`explicit()`][crate::pattern::explicit()] function instead because
+    /// it's more convenient.
+    pub fn new(mut dates: Vec<DateTime>) -> Explicit {
+        dates.sort_unstable();
+        dates.dedup();
+        Explicit { dates }
+    }
+
+    /// Adds a datetime to the pattern.
+    pub fn insert(&mut self, date: DateTime) {
+        if let Err(index) = self.dates.binary_search(&date) {
+            self.dates.insert(index, date);
+        }
+    }
+
+    /// Removes a datetime from the pattern.
+    ///
+    /// Returns `true` if the datetime was part of the pattern.
+    pub fn remove(&mut self, date: DateTime) -> bool {
+        match self.dates.binary_search(&date) {
+            Ok(index) => {
+                self.dates.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Returns `true` if the pattern contains `date`.
+    pub fn contains(&self, date: DateTime) -> bool {
+        self.dates.binary_search(&date).is_ok()
+    }
+
+    /// Returns the datetimes of the pattern in ascending order.
+    pub fn dates(&self) -> &[DateTime] {
+        &self.dates
+    }
+
+    /// Returns the first datetime at or after `lower_bound` which is within `range`.
+    fn first_from(&self, lower_bound: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let lower_bound = lower_bound.max(range.start);
+        let index = self.dates.partition_point(|&date| date < lower_bound);
+        self.dates
+            .get(index)
+            .copied()
+            .filter(|&date| date < range.end)
+    }
+}
+
+impl FromIterator<DateTime> for Explicit {
+    fn from_iter<I: IntoIterator<Item = DateTime>>(iter: I) -> Self {
+        Explicit::new(iter.into_iter().collect())
+    }
+}
+
+impl Extend<DateTime> for Explicit {
+    fn extend<I: IntoIterator<Item = DateTime>>(&mut self, iter: I) {
+        self.dates.extend(iter);
+        self.dates.sort_unstable();
+        self.dates.dedup();
+    }
+}
+
+impl Pattern for Explicit {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let index = self.dates.partition_point(|&date| date <= instant);
+        let next = *self.dates.get(index)?;
+        self.first_from(next, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let upper_bound = instant.min(range.end);
+        let index = self.dates.partition_point(|&date| date < upper_bound);
+        let previous = *self.dates.get(index.checked_sub(1)?)?;
+        (previous >= range.start).then_some(previous)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let next = self.first_from(instant, range);
+        let previous = self.previous_before(instant, range);
+        pick_best(previous, next, |previous, next| {
+            closest_to(instant, previous, next)
+        })
+    }
+}
+
+impl private::Sealed for Explicit {}
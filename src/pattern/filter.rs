@@ -0,0 +1,174 @@
+use crate::error::{Error, err};
+use crate::pattern::utils::{closest_to, next_at_or_after, pick_best};
+use crate::{DateTimeRange, Pattern, private};
+use core::fmt;
+use jiff::civil::DateTime;
+
+/// A recurrence pattern which only keeps the events of another pattern accepted by a predicate.
+///
+/// Values of this type are usually created via [`filter()`][crate::pattern::filter()].
+///
+/// Searching for an event skips over rejected events of the wrapped pattern until an accepted
+/// event or the end of the series range is found. To prevent a predicate which rejects all or
+/// almost all events from searching (nearly) forever, every search gives up after checking a
+/// limited number of events and returns no event. This budget defaults to
+/// [`Filter::DEFAULT_BUDGET`] and can be changed via [`.budget()`][Filter::budget].
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use recurring::{Event, Series, pattern::{daily, filter}};
+///
+/// let maintenance_days = [date(2025, 1, 2), date(2025, 1, 3)];
+/// let pattern = filter(daily(1), move |instant| !maintenance_days.contains(&instant.date()));
+///
+/// let series = Series::new(date(2025, 1, 1).at(12, 0, 0, 0).., pattern);
+///
+/// let mut events = series.iter();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(12, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 4).at(12, 0, 0, 0))));
+/// ```
+#[derive(Clone)]
+pub struct Filter<P, F> {
+    pattern: P,
+    predicate: F,
+    budget: usize,
+}
+
+impl<P, F> Filter<P, F>
+where
+    P: Pattern,
+    F: Fn(DateTime) -> bool + Clone,
+{
+    /// The default number of events checked by a single search.
+    pub const DEFAULT_BUDGET: usize = 10_000;
+
+    /// Create a new `Filter` which only keeps the events of `pattern` for which `predicate`
+    /// returns `true`.
+    ///
+    /// Consider using the [`filter()`][crate::pattern::filter()] function instead because it's
+    /// more convenient.
+    pub fn new(pattern: P, predicate: F) -> Filter<P, F> {
+        Filter {
+            pattern,
+            predicate,
+            budget: Self::DEFAULT_BUDGET,
+        }
+    }
+
+    /// Set the maximum number of events of the wrapped pattern checked by a single search.
+    ///
+    /// If a search does not find an accepted event within the budget, it gives up and returns no
+    /// event. This effectively ends the series at that point.
+    ///
+    /// The fallible version of this method is [`Filter::try_budget`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::{filter, hourly};
+    ///
+    /// let pattern = filter(hourly(1), |instant| instant.hour() == 12).budget(24);
+    /// ```
+    #[must_use]
+    pub fn budget(self, budget: usize) -> Filter<P, F> {
+        self.try_budget(budget).expect("invalid search budget")
+    }
+
+    /// Set the maximum number of events of the wrapped pattern checked by a single search.
+    ///
+    /// See [`.budget()`][Filter::budget] for details.
+    ///
+    /// The panicking version of this method is [`Filter::budget`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `budget` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::{filter, hourly};
+    ///
+    /// let pattern = filter(hourly(1), |instant| instant.hour() == 12).try_budget(24)?;
+    ///
+    /// assert!(pattern.try_budget(0).is_err());
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn try_budget(mut self, budget: usize) -> Result<Filter<P, F>, Error> {
+        if budget == 0 {
+            return Err(err!("search budget must be positive"));
+        }
+
+        self.budget = budget;
+        Ok(self)
+    }
+
+    /// Skips forward from `candidate`, which is an event of `pattern`, until it is accepted.
+    fn skip_forward(&self, mut candidate: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        for _ in 0..self.budget {
+            if (self.predicate)(candidate) {
+                return Some(candidate);
+            }
+
+            candidate = self.pattern.next_after(candidate, range)?;
+        }
+
+        None
+    }
+
+    /// Skips backward from `candidate`, which is an event of `pattern`, until it is accepted.
+    fn skip_backward(&self, mut candidate: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        for _ in 0..self.budget {
+            if (self.predicate)(candidate) {
+                return Some(candidate);
+            }
+
+            candidate = self.pattern.previous_before(candidate, range)?;
+        }
+
+        None
+    }
+}
+
+impl<P: fmt::Debug, F> fmt::Debug for Filter<P, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Filter")
+            .field("pattern", &self.pattern)
+            .field("budget", &self.budget)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P, F> Pattern for Filter<P, F>
+where
+    P: Pattern,
+    F: Fn(DateTime) -> bool + Clone,
+{
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let candidate = self.pattern.next_after(instant, range)?;
+        self.skip_forward(candidate, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let candidate = self.pattern.previous_before(instant, range)?;
+        self.skip_backward(candidate, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let next = next_at_or_after(&self.pattern, instant, range)
+            .and_then(|candidate| self.skip_forward(candidate, range));
+        let previous = self.previous_before(instant, range);
+        pick_best(previous, next, |previous, next| {
+            closest_to(instant, previous, next)
+        })
+    }
+}
+
+impl<P, F> private::Sealed for Filter<P, F> {}
@@ -0,0 +1,190 @@
+use crate::error::{Error, err};
+use crate::pattern::utils::{closest_to, next_at_or_after, pick_best, previous_at_or_before};
+use crate::{DateTimeRange, Pattern, private};
+use jiff::civil::DateTime;
+
+/// An intersection of two recurrence patterns.
+///
+/// This type is returned by the `.intersect()` method of the [`Combine`][crate::Combine] trait.
+/// It only produces events which are produced by both patterns.
+///
+/// Events are found by alternately advancing both patterns to the next event of the other until
+/// they agree. To prevent patterns which rarely or never produce the same events from searching
+/// (nearly) forever, every search gives up after a limited number of steps and returns no event.
+/// This budget defaults to [`Intersect::DEFAULT_BUDGET`] and can be changed via
+/// [`.budget()`][Intersect::budget].
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::{Weekday, date};
+/// use recurring::{Combine, Event, Series, pattern::{cron, minutely}};
+///
+/// let business_hours = cron()
+///     .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+///     .hours(9..=16);
+/// let pattern = minutely(15).intersect(business_hours);
+///
+/// // 2025-01-04 is a Saturday.
+/// let series = Series::new(date(2025, 1, 3).at(16, 30, 0, 0).., pattern);
+///
+/// let mut events = series.iter();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 3).at(16, 30, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 3).at(16, 45, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 6).at(9, 0, 0, 0))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Intersect<L, R> {
+    left: L,
+    right: R,
+    budget: usize,
+}
+
+impl<L, R> Intersect<L, R>
+where
+    L: Pattern,
+    R: Pattern,
+{
+    /// The default number of steps taken by a single search.
+    pub const DEFAULT_BUDGET: usize = 10_000;
+
+    /// Create a new `Intersect` from two recurrence patterns.
+    ///
+    /// Consider using the [`.intersect()`][crate::Combine::intersect] method of the `Combine`
+    /// trait instead because it's more convenient.
+    pub fn new(left: L, right: R) -> Intersect<L, R> {
+        Intersect {
+            left,
+            right,
+            budget: Self::DEFAULT_BUDGET,
+        }
+    }
+
+    /// Set the maximum number of steps taken by a single search.
+    ///
+    /// Every step advances both patterns once. If a search does not find a common event within the
+    /// budget, it gives up and returns no event. This effectively ends the series at that point.
+    ///
+    /// The fallible version of this method is [`Intersect::try_budget`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::{Combine, pattern::{cron, minutely}};
+    ///
+    /// let pattern = minutely(15).intersect(cron().hour(12)).budget(100);
+    /// ```
+    #[must_use]
+    pub fn budget(self, budget: usize) -> Intersect<L, R> {
+        self.try_budget(budget).expect("invalid search budget")
+    }
+
+    /// Set the maximum number of steps taken by a single search.
+    ///
+    /// See [`.budget()`][Intersect::budget] for details.
+    ///
+    /// The panicking version of this method is [`Intersect::budget`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `budget` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::{Combine, pattern::{cron, minutely}};
+    ///
+    /// let pattern = minutely(15).intersect(cron().hour(12)).try_budget(100)?;
+    ///
+    /// assert!(pattern.try_budget(0).is_err());
+    /// # Ok::<(), Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn try_budget(mut self, budget: usize) -> Result<Intersect<L, R>, Error> {
+        if budget == 0 {
+            return Err(err!("search budget must be positive"));
+        }
+
+        self.budget = budget;
+        Ok(self)
+    }
+
+    /// Returns the two intersected recurrence patterns.
+    pub fn into_parts(self) -> (L, R) {
+        (self.left, self.right)
+    }
+
+    /// Returns the maximum number of steps taken by a single search.
+    pub(crate) fn search_budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Finds the first common event at or after `candidate`, which is an event of `left`.
+    fn next_from(&self, mut candidate: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        for _ in 0..self.budget {
+            let right = next_at_or_after(&self.right, candidate, range)?;
+            if right == candidate {
+                return Some(candidate);
+            }
+
+            candidate = next_at_or_after(&self.left, right, range)?;
+        }
+
+        None
+    }
+
+    /// Finds the last common event at or before `candidate`, which is an event of `left`.
+    fn previous_from(&self, mut candidate: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        for _ in 0..self.budget {
+            let right = previous_at_or_before(&self.right, candidate, range)?;
+            if right == candidate {
+                return Some(candidate);
+            }
+
+            candidate = previous_at_or_before(&self.left, right, range)?;
+        }
+
+        None
+    }
+}
+
+impl<L, R> Pattern for Intersect<L, R>
+where
+    L: Pattern,
+    R: Pattern,
+{
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let candidate = self.left.next_after(instant, range)?;
+        self.next_from(candidate, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let candidate = self.left.previous_before(instant, range)?;
+        self.previous_from(candidate, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let next = next_at_or_after(&self.left, instant, range)
+            .and_then(|candidate| self.next_from(candidate, range));
+        let previous = self.previous_before(instant, range);
+        pick_best(previous, next, |previous, next| {
+            closest_to(instant, previous, next)
+        })
+    }
+}
+
+impl<L, R> Default for Intersect<L, R>
+where
+    L: Pattern + Default,
+    R: Pattern + Default,
+{
+    fn default() -> Self {
+        Intersect::new(L::default(), R::default())
+    }
+}
+
+impl<L, R> private::Sealed for Intersect<L, R> {}
@@ -179,8 +179,9 @@ impl Pattern for Interval {
         }
 
         let fixpoint = self.add_offset(range.fixpoint())?;
-        let date = advance_by_until(fixpoint, self.span, instant.min(range.end));
-        if date < instant {
+        let upper_bound = instant.min(range.end);
+        let date = advance_by_until(fixpoint, self.span, upper_bound);
+        if date < upper_bound {
             return Some(date);
         }
 
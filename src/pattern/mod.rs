@@ -1,17 +1,36 @@
 //! Patterns for recurring events.
 
+mod any;
 mod combined;
 mod cron;
+mod custom;
 mod daily;
+mod except;
+mod explicit;
+mod filter;
+mod intersect;
 mod interval;
 mod ranged;
+mod shift;
+mod union;
 mod utils;
+mod weekly;
 
+use crate::Pattern;
+pub use any::{AnyPattern, BuiltinPattern};
 pub use combined::Combined;
-pub use cron::Cron;
+pub use cron::{Cron, CronField, CronMacro, CronMismatch, DayMatch};
+pub use custom::{Custom, CustomPattern, check_pattern};
 pub use daily::Daily;
+pub use except::Except;
+pub use explicit::Explicit;
+pub use filter::Filter;
+pub use intersect::Intersect;
 pub use interval::Interval;
-use jiff::{Span, ToSpan};
+use jiff::{Span, ToSpan, civil::DateTime};
+pub use shift::Shift;
+pub use union::Union;
+pub use weekly::Weekly;
 
 /// Creates a cron recurrence pattern.
 ///
@@ -31,6 +50,91 @@ pub fn cron() -> Cron {
     Cron::new()
 }
 
+/// Creates a recurrence pattern from a user-defined [`CustomPattern`].
+///
+/// # Example
+///
+/// See the documentation of [`CustomPattern`].
+#[inline]
+pub fn custom<T: CustomPattern>(pattern: T) -> Custom<T> {
+    Custom::new(pattern)
+}
+
+/// Creates a union of recurrence patterns which produces the events of all of them.
+///
+/// # Example
+///
+/// ```
+/// use recurring::pattern::{cron, union};
+///
+/// let mornings_and_evenings = union([cron().hour(8), cron().hour(20)]);
+/// ```
+#[inline]
+pub fn union<P: Pattern, I: IntoIterator<Item = P>>(patterns: I) -> Union<P> {
+    patterns.into_iter().collect()
+}
+
+/// Creates a recurrence pattern for events at an explicit list of datetimes.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use recurring::pattern::explicit;
+///
+/// let board_meetings = explicit([
+///     date(2025, 3, 12).at(9, 0, 0, 0),
+///     date(2025, 6, 18).at(9, 0, 0, 0),
+///     date(2025, 11, 5).at(14, 0, 0, 0),
+/// ]);
+/// ```
+#[inline]
+pub fn explicit<I: IntoIterator<Item = DateTime>>(dates: I) -> Explicit {
+    dates.into_iter().collect()
+}
+
+/// Creates a recurrence pattern which only keeps the events of `pattern` for which `predicate`
+/// returns `true`.
+///
+/// See the documentation of [`Filter`] for details.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::Weekday;
+/// use recurring::pattern::{daily, filter};
+///
+/// let weekends = filter(daily(1), |instant| {
+///     matches!(instant.weekday(), Weekday::Saturday | Weekday::Sunday)
+/// });
+/// ```
+#[inline]
+pub fn filter<P, F>(pattern: P, predicate: F) -> Filter<P, F>
+where
+    P: Pattern,
+    F: Fn(DateTime) -> bool + Clone,
+{
+    Filter::new(pattern, predicate)
+}
+
+/// Creates a recurrence pattern which moves every event of `pattern` by a signed `offset`.
+///
+/// See the documentation of [`Shift`] for details.
+///
+/// # Example
+///
+/// ```
+/// use jiff::ToSpan;
+/// use recurring::pattern::{cron, shift};
+///
+/// // The day after the last day of every month.
+/// let after_month_end = shift(cron().day(-1).hour(0).minute(0).second(0), 1.day());
+/// ```
+#[inline]
+pub fn shift<P: Pattern>(pattern: P, offset: Span) -> Shift<P> {
+    Shift::new(pattern, offset)
+}
+
 /// Creates a recurrence pattern for events recurring on a fixed interval.
 ///
 /// # Panics
@@ -122,6 +226,27 @@ pub fn daily<I: ToSpan>(interval: I) -> Daily {
     Daily::new(interval)
 }
 
+/// Creates a recurrence pattern for events recurring on a weekly basis.
+///
+/// # Panics
+///
+/// Panics if `interval` is negative or zero.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::{Weekday, time};
+/// use recurring::pattern::weekly;
+///
+/// let every_two_weeks_on_tuesday_and_thursday_at_ten = weekly(2)
+///     .on([Weekday::Tuesday, Weekday::Thursday])
+///     .at(time(10, 0, 0, 0));
+/// ```
+#[inline]
+pub fn weekly<I: ToSpan>(interval: I) -> Weekly {
+    Weekly::new(interval)
+}
+
 /// Creates a recurrence pattern for events recurring on a monthly basis.
 ///
 /// # Panics
@@ -1,63 +1,202 @@
 use crate::error::Error;
-use alloc::collections::btree_set::{self, BTreeSet};
+use alloc::sync::Arc;
+use alloc::vec;
 use core::ops::RangeInclusive;
 
 pub(super) type Years = RangedI16Set<-9999, 9999>;
 pub(super) type Months = RangedI8Set<1, 12>;
 pub(super) type Weekdays = RangedI8Set<1, 7>;
 pub(super) type Days = RangedI8Set<1, 31>;
+pub(super) type DaysOfYear = RangedI16Set<1, 366>;
+pub(super) type IsoWeeks = RangedI8Set<1, 53>;
 pub(super) type Hours = RangedI8Set<0, 23>;
 pub(super) type Minutes = RangedI8Set<0, 59>;
 pub(super) type Seconds = RangedI8Set<0, 59>;
+pub(super) type Milliseconds = RangedI16Set<0, 999>;
 
 /// A ranged set of i8 values.
 ///
+/// The values are stored in a 64 bit bitset, where bit `n` represents the value `MIN + n`. Thus,
+/// `MAX - MIN` must be smaller than `64`. Excluded values are stored in a separate bitset, so
+/// the set contains the same values regardless of the order of insertions and exclusions.
+///
 /// The `Default` value of this type contains the full range of possible values between `MIN`
 /// (inclusive) and `MAX` (inclusive).
-#[derive(Debug, Clone, Default)]
-pub(super) struct RangedI8Set<const MIN: i8, const MAX: i8>(BTreeSet<i8>);
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct RangedI8Set<const MIN: i8, const MAX: i8> {
+    values: u64,
+    excluded: u64,
+}
 
 impl<const MIN: i8, const MAX: i8> RangedI8Set<MIN, MAX> {
     pub(super) const MIN: i8 = MIN;
     pub(super) const MAX: i8 = MAX;
 
+    /// The bitset containing all values between `MIN` and `MAX`.
+    ///
+    /// Evaluating this fails to compile if the range does not fit into 64 bits.
+    const FULL: u64 = u64::MAX >> (63 - MAX.abs_diff(MIN));
+
     #[inline]
     fn is_within_bounds(value: i8) -> bool {
         (Self::MIN..=Self::MAX).contains(&value)
     }
 
-    pub(super) fn try_insert(&mut self, value: i8) -> Result<bool, Error> {
-        if Self::is_within_bounds(value) {
-            Ok(self.0.insert(value))
+    #[inline]
+    fn bit(value: i8) -> u64 {
+        1 << value.abs_diff(MIN)
+    }
+
+    /// Returns the bitset of matching values, taking into account that a set without inserted
+    /// values matches every value which is not excluded.
+    #[inline]
+    fn bits(self) -> u64 {
+        let values = if self.values == 0 {
+            Self::FULL
         } else {
-            Err(Error::range(value, Self::MIN, Self::MAX))
+            self.values
+        };
+
+        values & !self.excluded
+    }
+
+    /// Returns `true` if the set matches every value between `MIN` and `MAX`.
+    pub(super) fn is_full(self) -> bool {
+        self.bits() == Self::FULL
+    }
+
+    /// Returns `true` if no value was inserted into the set. Excluded values are not taken into
+    /// account.
+    pub(super) fn is_empty(self) -> bool {
+        self.values == 0
+    }
+
+    /// Returns `true` if any value was excluded from the set.
+    pub(super) fn has_exclusions(self) -> bool {
+        self.excluded != 0
+    }
+
+    pub(super) fn try_insert(&mut self, value: i8) -> Result<bool, Error> {
+        if !Self::is_within_bounds(value) {
+            return Err(Error::range(value, Self::MIN, Self::MAX));
         }
+
+        let bit = Self::bit(value);
+        let inserted = self.values & bit == 0;
+        self.values |= bit;
+        Ok(inserted)
     }
 
-    pub(super) fn contains(&self, value: i8) -> bool {
-        if self.0.is_empty() {
-            Self::is_within_bounds(value)
-        } else {
-            self.0.contains(&value)
+    /// Excludes a value from the set, regardless of whether it was inserted before or after.
+    pub(super) fn try_exclude(&mut self, value: i8) -> Result<bool, Error> {
+        if !Self::is_within_bounds(value) {
+            return Err(Error::range(value, Self::MIN, Self::MAX));
         }
+
+        let bit = Self::bit(value);
+        let excluded = self.excluded & bit == 0;
+        self.excluded |= bit;
+        Ok(excluded)
     }
 
-    pub(super) fn range(&self, range: RangeInclusive<i8>) -> RangeIter<'_, i8> {
-        if self.0.is_empty() {
-            let (start, end) = range.into_inner();
-            RangeIter::Range(start.max(Self::MIN)..=end.min(Self::MAX))
-        } else {
-            RangeIter::SetRange(self.0.range(range))
+    /// Returns `true` if `value` was excluded from the set.
+    pub(super) fn is_excluded(self, value: i8) -> bool {
+        Self::is_within_bounds(value) && self.excluded & Self::bit(value) != 0
+    }
+
+    pub(super) fn contains(self, value: i8) -> bool {
+        Self::is_within_bounds(value) && self.bits() & Self::bit(value) != 0
+    }
+
+    pub(super) fn range(self, range: RangeInclusive<i8>) -> BitIter {
+        let (start, end) = range.into_inner();
+        let (start, end) = (start.max(Self::MIN), end.min(Self::MAX));
+
+        if start > end {
+            return BitIter { bits: 0, min: MIN };
+        }
+
+        // Mask out all bits below `start` and above `end`.
+        let mask = (Self::FULL << start.abs_diff(MIN)) & (u64::MAX >> (63 - end.abs_diff(MIN)));
+
+        BitIter {
+            bits: self.bits() & mask,
+            min: MIN,
+        }
+    }
+}
+
+// Sets are equal if they match the same values, regardless of whether they were restricted.
+impl<const MIN: i8, const MAX: i8> PartialEq for RangedI8Set<MIN, MAX> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl<const MIN: i8, const MAX: i8> Eq for RangedI8Set<MIN, MAX> {}
+
+/// Iterator over the values of a [`RangedI8Set`].
+///
+/// Finds the next and previous values via the trailing and leading zeros of the remaining bits.
+#[derive(Clone)]
+pub(super) struct BitIter {
+    bits: u64,
+    min: i8,
+}
+
+impl BitIter {
+    fn value(&self, index: u32) -> Option<i8> {
+        u8::try_from(index)
+            .ok()
+            .map(|index| self.min.wrapping_add_unsigned(index))
+    }
+}
+
+impl Iterator for BitIter {
+    type Item = i8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = self.bits.trailing_zeros();
+        self.bits ^= 1 << index;
+        self.value(index)
+    }
+}
+
+impl DoubleEndedIterator for BitIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.bits == 0 {
+            return None;
         }
+
+        let index = self.bits.ilog2();
+        self.bits ^= 1 << index;
+        self.value(index)
     }
 }
 
 /// A ranged set of i16 values.
 ///
+/// Restricted sets store their values in a shared bitset, where bit `n` represents the value
+/// `MIN + n`. The bitset is only copied when a shared set is modified, so cloning is cheap.
+/// Excluded values are stored in a separate bitset, so the set contains the same values
+/// regardless of the order of insertions and exclusions.
+///
 /// The `Default` value of this type contains the full range of possible values between `MIN`
 /// (inclusive) and `MAX` (inclusive).
 #[derive(Debug, Clone, Default)]
-pub(super) struct RangedI16Set<const MIN: i16, const MAX: i16>(BTreeSet<i16>);
+pub(super) struct RangedI16Set<const MIN: i16, const MAX: i16> {
+    /// The values in the set, or `None` if the set contains every value.
+    words: Option<Arc<[u64]>>,
+    /// The excluded values, or `None` if no value was excluded.
+    excluded: Option<Arc<[u64]>>,
+    /// Whether any value was inserted. Otherwise `words` contains every value which is not
+    /// excluded.
+    inserted: bool,
+}
 
 impl<const MIN: i16, const MAX: i16> RangedI16Set<MIN, MAX> {
     pub(super) const MIN: i16 = MIN;
@@ -68,51 +207,355 @@ impl<const MIN: i16, const MAX: i16> RangedI16Set<MIN, MAX> {
         (Self::MIN..=Self::MAX).contains(&value)
     }
 
+    #[inline]
+    fn index(value: i16) -> usize {
+        usize::from(value.abs_diff(MIN))
+    }
+
+    /// Returns the word and bit of `value` in the bitset.
+    #[inline]
+    fn position(value: i16) -> (usize, u64) {
+        let index = Self::index(value);
+        (index / 64, 1 << (index % 64))
+    }
+
+    /// Returns a bitset without any value.
+    fn empty_words() -> Arc<[u64]> {
+        Arc::from(vec![0; Self::index(MAX) / 64 + 1])
+    }
+
+    /// Returns a bitset with every value between `MIN` and `MAX`.
+    fn full_words() -> Arc<[u64]> {
+        let last = Self::index(MAX);
+        let mut words = vec![u64::MAX; last / 64 + 1];
+        // Clear the bits beyond `MAX` in the last word.
+        words[last / 64] = u64::MAX >> (63 - last % 64);
+        Arc::from(words)
+    }
+
+    /// Returns `true` if no value was inserted into the set. Excluded values are not taken into
+    /// account.
+    pub(super) fn is_empty(&self) -> bool {
+        !self.inserted
+    }
+
+    /// Returns `true` if the set matches every value between `MIN` and `MAX`.
+    pub(super) fn is_full(&self) -> bool {
+        self.words.as_ref().is_none_or(|words| {
+            let len = Self::index(MAX) + 1;
+            words
+                .iter()
+                .map(|word| word.count_ones() as usize)
+                .sum::<usize>()
+                == len
+        })
+    }
+
     pub(super) fn try_insert(&mut self, value: i16) -> Result<bool, Error> {
-        if Self::is_within_bounds(value) {
-            Ok(self.0.insert(value))
+        if !Self::is_within_bounds(value) {
+            return Err(Error::range(value, Self::MIN, Self::MAX));
+        }
+
+        if !self.inserted {
+            // The set contained every value which is not excluded so far.
+            self.words = Some(Self::empty_words());
+            self.inserted = true;
+        }
+
+        if self.is_excluded(value) {
+            return Ok(false);
+        }
+
+        let (word, bit) = Self::position(value);
+        let words = self.words.get_or_insert_with(Self::empty_words);
+
+        if words[word] & bit != 0 {
+            return Ok(false);
+        }
+
+        Self::update(words, |words| words[word] |= bit);
+        Ok(true)
+    }
+
+    /// Excludes a value from the set, regardless of whether it was inserted before or after.
+    pub(super) fn try_exclude(&mut self, value: i16) -> Result<bool, Error> {
+        if !Self::is_within_bounds(value) {
+            return Err(Error::range(value, Self::MIN, Self::MAX));
+        }
+
+        if self.is_excluded(value) {
+            return Ok(false);
+        }
+
+        let (word, bit) = Self::position(value);
+
+        let excluded = self.excluded.get_or_insert_with(Self::empty_words);
+        Self::update(excluded, |excluded| excluded[word] |= bit);
+
+        let words = self.words.get_or_insert_with(Self::full_words);
+        if words[word] & bit != 0 {
+            Self::update(words, |words| words[word] &= !bit);
+        }
+
+        Ok(true)
+    }
+
+    /// Returns `true` if `value` was excluded from the set.
+    fn is_excluded(&self, value: i16) -> bool {
+        let (word, bit) = Self::position(value);
+        self.excluded
+            .as_ref()
+            .is_some_and(|excluded| excluded[word] & bit != 0)
+    }
+
+    pub(super) fn contains(&self, value: i16) -> bool {
+        if !Self::is_within_bounds(value) {
+            return false;
+        }
+
+        let (word, bit) = Self::position(value);
+        self.words
+            .as_ref()
+            .is_none_or(|words| words[word] & bit != 0)
+    }
+
+    /// Modifies the words of the bitset, copying them first if they are shared.
+    fn update(words: &mut Arc<[u64]>, f: impl FnOnce(&mut [u64])) {
+        if let Some(words) = Arc::get_mut(words) {
+            f(words);
         } else {
-            Err(Error::range(value, Self::MIN, Self::MAX))
+            let mut copy = words.to_vec();
+            f(&mut copy);
+            *words = Arc::from(copy);
         }
     }
 
-    pub(super) fn range(&self, range: RangeInclusive<i16>) -> RangeIter<'_, i16> {
-        if self.0.is_empty() {
-            let (start, end) = range.into_inner();
-            RangeIter::Range(start.max(Self::MIN)..=end.min(Self::MAX))
+    pub(super) fn range(&self, range: RangeInclusive<i16>) -> RangeIter<'_> {
+        let (start, end) = range.into_inner();
+        let (start, end) = (start.max(Self::MIN), end.min(Self::MAX));
+
+        let Some(words) = &self.words else {
+            return RangeIter::Range(start..=end);
+        };
+
+        let (front, back) = if start > end {
+            (0, 0)
         } else {
-            RangeIter::SetRange(self.0.range(range))
+            (Self::index(start), Self::index(end) + 1)
+        };
+
+        RangeIter::Bits {
+            words,
+            front,
+            back,
+            min: MIN,
         }
     }
 }
 
-pub(super) enum RangeIter<'a, T> {
-    Range(RangeInclusive<T>),
-    SetRange(btree_set::Range<'a, T>),
+// Sets are equal if they match the same values, regardless of whether they were restricted.
+impl<const MIN: i16, const MAX: i16> PartialEq for RangedI16Set<MIN, MAX> {
+    fn eq(&self, other: &Self) -> bool {
+        self.range(MIN..=MAX).eq(other.range(MIN..=MAX))
+    }
 }
 
-impl<T: Copy> Iterator for RangeIter<'_, T>
-where
-    RangeInclusive<T>: Iterator<Item = T>,
-{
-    type Item = T;
+impl<const MIN: i16, const MAX: i16> Eq for RangedI16Set<MIN, MAX> {}
+
+/// Iterator over the values of a [`RangedI16Set`].
+#[derive(Clone)]
+pub(super) enum RangeIter<'a> {
+    Range(RangeInclusive<i16>),
+    /// Bits from `front` (inclusive) to `back` (exclusive) which have not been visited yet.
+    Bits {
+        words: &'a [u64],
+        front: usize,
+        back: usize,
+        min: i16,
+    },
+}
+
+impl Iterator for RangeIter<'_> {
+    type Item = i16;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self {
-            RangeIter::Range(range) => range.next(),
-            RangeIter::SetRange(iter) => iter.next().copied(),
+        let (words, front, back, min) = match self {
+            RangeIter::Range(range) => return range.next(),
+            RangeIter::Bits {
+                words,
+                front,
+                back,
+                min,
+            } => (*words, front, back, *min),
+        };
+
+        while *front < *back {
+            let word = words[*front / 64] >> (*front % 64);
+            if word == 0 {
+                *front = (*front / 64 + 1) * 64;
+                continue;
+            }
+
+            let index = *front + word.trailing_zeros() as usize;
+            if index >= *back {
+                break;
+            }
+
+            *front = index + 1;
+            return u16::try_from(index)
+                .ok()
+                .map(|index| min.wrapping_add_unsigned(index));
         }
+
+        *front = *back;
+        None
     }
 }
 
-impl<T: Copy> DoubleEndedIterator for RangeIter<'_, T>
-where
-    RangeInclusive<T>: DoubleEndedIterator<Item = T>,
-{
+impl DoubleEndedIterator for RangeIter<'_> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        match self {
-            RangeIter::Range(range) => range.next_back(),
-            RangeIter::SetRange(iter) => iter.next_back().copied(),
+        let (words, front, back, min) = match self {
+            RangeIter::Range(range) => return range.next_back(),
+            RangeIter::Bits {
+                words,
+                front,
+                back,
+                min,
+            } => (*words, front, back, *min),
+        };
+
+        while *front < *back {
+            let last = *back - 1;
+            let word = words[last / 64] << (63 - last % 64);
+            if word == 0 {
+                *back = last / 64 * 64;
+                continue;
+            }
+
+            let Some(index) = last.checked_sub(word.leading_zeros() as usize) else {
+                break;
+            };
+
+            if index < *front {
+                break;
+            }
+
+            *back = index;
+            return u16::try_from(index)
+                .ok()
+                .map(|index| min.wrapping_add_unsigned(index));
+        }
+
+        *back = *front;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_ranged_i8_set() {
+        let mut set = Minutes::default();
+        assert!(set.is_full());
+        assert_eq!(set.range(58..=70).collect::<Vec<_>>(), [58, 59]);
+
+        for value in [0, 15, 30, 59] {
+            assert_eq!(set.try_insert(value).unwrap(), true);
+        }
+        assert_eq!(set.try_insert(15).unwrap(), false);
+        assert!(set.try_insert(60).is_err());
+
+        assert!(!set.is_full());
+        assert!(set.contains(59));
+        assert!(!set.contains(16));
+        assert_eq!(set.range(1..=59).collect::<Vec<_>>(), [15, 30, 59]);
+        assert_eq!(set.range(0..=30).rev().collect::<Vec<_>>(), [30, 15, 0]);
+        assert_eq!(set.range(31..=58).next(), None);
+    }
+
+    #[test]
+    fn test_ranged_i8_set_exclude() {
+        let mut set = Hours::default();
+        assert_eq!(set.try_exclude(12).unwrap(), true);
+        assert_eq!(set.try_exclude(12).unwrap(), false);
+        assert!(set.try_exclude(24).is_err());
+        assert!(set.is_empty());
+        assert!(!set.is_full());
+        assert!(!set.contains(12));
+        assert_eq!(set.range(11..=13).collect::<Vec<_>>(), [11, 13]);
+
+        // Values are excluded regardless of the order of insertions and exclusions.
+        for value in 9..=17 {
+            set.try_insert(value).unwrap();
+        }
+        assert!(!set.is_empty());
+        assert!(set.is_excluded(12));
+        assert_eq!(
+            set.range(Hours::MIN..=Hours::MAX).collect::<Vec<_>>(),
+            [9, 10, 11, 13, 14, 15, 16, 17]
+        );
+    }
+
+    #[test]
+    fn test_ranged_i16_set() {
+        let mut set = Years::default();
+        assert!(set.is_full());
+        assert_eq!(set.range(9998..=10000).collect::<Vec<_>>(), [9998, 9999]);
+
+        for value in [-9999, -9936, 2025, 2089, 9999] {
+            assert_eq!(set.try_insert(value).unwrap(), true);
+        }
+        assert_eq!(set.try_insert(2025).unwrap(), false);
+        assert!(set.try_insert(10000).is_err());
+
+        let clone = set.clone();
+        set.try_insert(2030).unwrap();
+
+        assert!(!set.is_full());
+        assert_eq!(
+            set.range(Years::MIN..=Years::MAX).collect::<Vec<_>>(),
+            [-9999, -9936, 2025, 2030, 2089, 9999]
+        );
+        assert_eq!(
+            clone.range(-9936..=2089).rev().collect::<Vec<_>>(),
+            [2089, 2025, -9936]
+        );
+        assert_eq!(set.range(2026..=2029).next(), None);
+        assert_eq!(set.range(2026..=2029).next_back(), None);
+        assert_eq!(set.range(-10001..=-10000).next(), None);
+
+        assert!(set.contains(2030));
+        assert!(!clone.contains(2030));
+        assert!(!set.contains(10000));
+        assert!(Years::default().contains(0));
+    }
+
+    #[test]
+    fn test_ranged_i16_set_exclude() {
+        let mut set = Years::default();
+        assert_eq!(set.try_exclude(2026).unwrap(), true);
+        assert_eq!(set.try_exclude(2026).unwrap(), false);
+        assert!(set.try_exclude(10000).is_err());
+        assert!(set.is_empty());
+        assert!(!set.is_full());
+        assert!(!set.contains(2026));
+        assert_eq!(set.range(2025..=2027).collect::<Vec<_>>(), [2025, 2027]);
+
+        // Values are excluded regardless of the order of insertions and exclusions.
+        let clone = set.clone();
+        for value in 2025..=2027 {
+            set.try_insert(value).unwrap();
         }
+        assert!(!set.is_empty());
+        assert_eq!(
+            set.range(Years::MIN..=Years::MAX).collect::<Vec<_>>(),
+            [2025, 2027]
+        );
+        assert!(clone.contains(2030));
+        assert!(!set.contains(2030));
     }
 }
@@ -0,0 +1,180 @@
+use crate::pattern::utils::{closest_to, next_at_or_after, pick_best};
+use crate::{DateTimeRange, Pattern, private};
+use jiff::{Span, ToSpan, civil::DateTime};
+
+/// A recurrence pattern which moves every event of another pattern by a signed offset.
+///
+/// Values of this type are usually created via [`shift()`][crate::pattern::shift()].
+///
+/// The wrapped pattern is evaluated on the series range moved by the negated offset, so that all
+/// of its events which end up within the series range after shifting are found. Events which are
+/// shifted outside of the series range are never produced.
+///
+/// The fixpoint of the series is not moved unless it is after the start of the moved range, in
+/// which case the moved range start is used instead. With a negative offset, patterns relative to
+/// the fixpoint like [`Interval`][crate::pattern::Interval] thus produce their events relative to
+/// the same fixpoint as without the shift. With a positive offset and no explicit fixpoint, they
+/// are anchored to the moved range start, which cancels out the shift. Use
+/// [`Interval::offset`][crate::pattern::Interval::offset] to move intervals forward instead.
+///
+/// # Example
+///
+/// ```
+/// use jiff::{ToSpan, civil::date};
+/// use recurring::{Event, Series, pattern::{cron, shift}};
+///
+/// // Two hours before every meeting at 10:00 and 15:00.
+/// let meetings = cron().hours([10, 15]).minute(0).second(0);
+/// let reminders = shift(meetings, -2.hours());
+///
+/// let series = Series::new(date(2025, 1, 1).at(9, 0, 0, 0).., reminders);
+///
+/// let mut events = series.iter();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(13, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 2).at(8, 0, 0, 0))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Shift<P> {
+    pattern: P,
+    offset: Span,
+}
+
+impl<P> Shift<P>
+where
+    P: Pattern,
+{
+    /// Create a new `Shift` which moves every event of `pattern` by `offset`.
+    ///
+    /// Consider using the [`shift()`][crate::pattern::shift()] function instead because it's more
+    /// convenient.
+    pub fn new(pattern: P, offset: Span) -> Shift<P> {
+        Shift { pattern, offset }
+    }
+
+    /// Returns the offset by which events are moved.
+    pub fn offset(&self) -> Span {
+        self.offset
+    }
+
+    /// Returns the wrapped pattern.
+    pub fn into_inner(self) -> P {
+        self.pattern
+    }
+
+    /// Moves `instant` by the negated offset, saturating at the `DateTime` bounds.
+    fn unshift(&self, instant: DateTime) -> DateTime {
+        instant
+            .checked_sub(self.offset)
+            .unwrap_or(if self.offset.is_positive() {
+                DateTime::MIN
+            } else {
+                DateTime::MAX
+            })
+    }
+
+    /// Returns the bound before which the wrapped pattern is searched for events which are moved
+    /// before `instant`.
+    ///
+    /// Adding months or years clamps the day to the end of the month, so events up to three days
+    /// after the unshifted `instant` may still be moved before `instant`.
+    fn unshift_upper_bound(&self, instant: DateTime) -> DateTime {
+        let unshifted = self.unshift(instant);
+        if self.offset.get_years() == 0 && self.offset.get_months() == 0 {
+            return unshifted;
+        }
+
+        unshifted.checked_add(3.days()).unwrap_or(DateTime::MAX)
+    }
+
+    /// Returns the range in which the wrapped pattern is evaluated.
+    fn unshift_range(&self, range: DateTimeRange) -> Option<DateTimeRange> {
+        let start = self.unshift(range.start);
+        let end = self.unshift(range.end);
+        if start >= end {
+            return None;
+        }
+
+        let fixpoint = range.fixpoint().min(start);
+        DateTimeRange::new(start, end).with_fixpoint(fixpoint).ok()
+    }
+
+    /// Finds the first shifted event which is within `range` and after `instant` (or at `instant`
+    /// if `inclusive` is `true`), starting at `event` of the wrapped pattern.
+    fn find_forward(
+        &self,
+        mut event: DateTime,
+        instant: DateTime,
+        inclusive: bool,
+        (range, inner): (DateTimeRange, DateTimeRange),
+    ) -> Option<DateTime> {
+        loop {
+            match event.checked_add(self.offset) {
+                Ok(shifted) if shifted >= range.end => return None,
+                Ok(shifted)
+                    if shifted >= range.start
+                        && (shifted > instant || (inclusive && shifted == instant)) =>
+                {
+                    return Some(shifted);
+                }
+                // Later events would overflow as well.
+                Err(_) if self.offset.is_positive() => return None,
+                _ => {}
+            }
+
+            event = self.pattern.next_after(event, inner)?;
+        }
+    }
+
+    /// Finds the last shifted event which is within `range` and before `instant`, starting at
+    /// `event` of the wrapped pattern.
+    fn find_backward(
+        &self,
+        mut event: DateTime,
+        instant: DateTime,
+        (range, inner): (DateTimeRange, DateTimeRange),
+    ) -> Option<DateTime> {
+        loop {
+            match event.checked_add(self.offset) {
+                Ok(shifted) if shifted < range.start => return None,
+                Ok(shifted) if shifted < range.end && shifted < instant => return Some(shifted),
+                // Earlier events would overflow as well.
+                Err(_) if self.offset.is_negative() => return None,
+                _ => {}
+            }
+
+            event = self.pattern.previous_before(event, inner)?;
+        }
+    }
+}
+
+impl<P> Pattern for Shift<P>
+where
+    P: Pattern,
+{
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let inner = self.unshift_range(range)?;
+        let event = self.pattern.next_after(self.unshift(instant), inner)?;
+        self.find_forward(event, instant, false, (range, inner))
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let inner = self.unshift_range(range)?;
+        let event = self
+            .pattern
+            .previous_before(self.unshift_upper_bound(instant), inner)?;
+        self.find_backward(event, instant, (range, inner))
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let inner = self.unshift_range(range)?;
+        let next = next_at_or_after(&self.pattern, self.unshift(instant), inner)
+            .and_then(|event| self.find_forward(event, instant, true, (range, inner)));
+        let previous = self.previous_before(instant, range);
+        pick_best(previous, next, |previous, next| {
+            closest_to(instant, previous, next)
+        })
+    }
+}
+
+impl<P> private::Sealed for Shift<P> {}
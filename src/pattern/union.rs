@@ -0,0 +1,121 @@
+use crate::pattern::utils::closest_to;
+use crate::{DateTimeRange, Pattern, private};
+use alloc::vec::Vec;
+use jiff::civil::DateTime;
+
+/// A union of any number of recurrence patterns of the same type.
+///
+/// Unlike [`Combined`][crate::pattern::Combined], which nests pairs of patterns, a `Union` holds
+/// its patterns in a flat list. Use [`AnyPattern`][crate::pattern::AnyPattern] or
+/// [`BuiltinPattern`][crate::pattern::BuiltinPattern] to combine patterns of different types.
+///
+/// The `Pattern` methods query every pattern of the union, so they take O(n) time in the number of
+/// patterns. This applies to every step of [`Series::iter`][crate::Series::iter] and
+/// [`Series::range`][crate::Series::range], to lookups like
+/// [`Series::get_next_after`][crate::Series::get_next_after], and to unions nested in other
+/// patterns. Only [`Series::iter_merged`][crate::Series::iter_merged] keeps the next event of every
+/// pattern in a heap, which brings each step down to O(log n) after an initial O(n) pass.
+///
+/// A union without any patterns produces no events.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::date;
+/// use recurring::{Event, Series, pattern::{cron, union}};
+///
+/// let pattern = union([
+///     cron().hour(8).minute(0).second(0),
+///     cron().hour(12).minute(30).second(0),
+///     cron().hour(18).minute(0).second(0),
+/// ]);
+///
+/// let series = Series::new(date(2025, 1, 1).at(0, 0, 0, 0).., pattern);
+///
+/// let mut events = series.iter_merged();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(8, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(12, 30, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 1).at(18, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 2).at(8, 0, 0, 0))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Union<P> {
+    patterns: Vec<P>,
+}
+
+impl<P> Union<P>
+where
+    P: Pattern,
+{
+    /// Create a new `Union` from a list of recurrence patterns.
+    ///
+    /// Consider using the [`union()`][crate::pattern::union()] function instead because it's more
+    /// convenient.
+    pub fn new(patterns: Vec<P>) -> Union<P> {
+        Union { patterns }
+    }
+
+    /// Adds another recurrence pattern to the union.
+    pub fn push(&mut self, pattern: P) {
+        self.patterns.push(pattern);
+    }
+
+    /// Returns the recurrence patterns of the union.
+    pub fn patterns(&self) -> &[P] {
+        &self.patterns
+    }
+
+    /// Returns the recurrence patterns of the union.
+    pub fn into_patterns(self) -> Vec<P> {
+        self.patterns
+    }
+}
+
+impl<P> Default for Union<P> {
+    fn default() -> Self {
+        Union {
+            patterns: Vec::new(),
+        }
+    }
+}
+
+impl<P: Pattern> FromIterator<P> for Union<P> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Union::new(iter.into_iter().collect())
+    }
+}
+
+impl<P: Pattern> Extend<P> for Union<P> {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        self.patterns.extend(iter);
+    }
+}
+
+impl<P> Pattern for Union<P>
+where
+    P: Pattern,
+{
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.patterns
+            .iter()
+            .filter_map(|pattern| pattern.next_after(instant, range))
+            .min()
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.patterns
+            .iter()
+            .filter_map(|pattern| pattern.previous_before(instant, range))
+            .max()
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.patterns
+            .iter()
+            .filter_map(|pattern| pattern.closest_to(instant, range))
+            .reduce(|left, right| closest_to(instant, left, right))
+    }
+}
+
+impl<P> private::Sealed for Union<P> {}
@@ -1,4 +1,5 @@
-use jiff::{Span, civil::DateTime};
+use crate::{DateTimeRange, Pattern};
+use jiff::{Span, ToSpan, civil::DateTime};
 
 /// Multiplier to increase the spans added to a given `DateTime` in bulk by the
 /// `advance_until_` functions. This was chosen arbitrarily but seems to be plenty fast for the
@@ -114,6 +115,34 @@ pub(super) fn pick_best<F: FnOnce(DateTime, DateTime) -> DateTime>(
     }
 }
 
+/// Finds the next `DateTime` of `pattern` at or after `instant` within a range.
+#[inline]
+pub(super) fn next_at_or_after<P: Pattern>(
+    pattern: &P,
+    instant: DateTime,
+    range: DateTimeRange,
+) -> Option<DateTime> {
+    match instant.checked_sub(1.nanosecond()) {
+        Ok(before) => pattern.next_after(before, range),
+        // `instant` is `DateTime::MIN`, so the closest event can't be before it.
+        Err(_) => pattern.closest_to(instant, range),
+    }
+}
+
+/// Finds the previous `DateTime` of `pattern` at or before `instant` within a range.
+#[inline]
+pub(super) fn previous_at_or_before<P: Pattern>(
+    pattern: &P,
+    instant: DateTime,
+    range: DateTimeRange,
+) -> Option<DateTime> {
+    match instant.checked_add(1.nanosecond()) {
+        Ok(after) => pattern.previous_before(after, range),
+        // `instant` is `DateTime::MAX`, so the closest event can't be after it.
+        Err(_) => pattern.closest_to(instant, range),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
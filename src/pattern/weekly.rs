@@ -0,0 +1,324 @@
+use crate::error::{Error, err};
+use crate::pattern::ranged::Weekdays;
+use crate::pattern::utils::{closest_to, pick_best};
+use crate::{DateTimeRange, Pattern, private};
+use jiff::{
+    ToSpan,
+    civil::{Date, DateTime, Time, Weekday},
+};
+
+/// A recurrence pattern for weekly events on selected weekdays.
+///
+/// Like [`Interval`][crate::pattern::Interval], this pattern is anchored to the fixpoint of the
+/// series: the week containing the fixpoint is the first week with events, followed by every
+/// `interval`'th week after it. Unless configured otherwise, events happen on the weekday and at
+/// the time of day of the fixpoint.
+///
+/// # Example
+///
+/// ```
+/// use jiff::civil::{Weekday, date, time};
+/// use recurring::{Event, Series, pattern::weekly};
+///
+/// // Every two weeks on Tuesday and Thursday at 10:00.
+/// let pattern = weekly(2)
+///     .on([Weekday::Tuesday, Weekday::Thursday])
+///     .at(time(10, 0, 0, 0));
+///
+/// // 2025-01-01 is a Wednesday.
+/// let series = Series::new(date(2025, 1, 1).at(0, 0, 0, 0).., pattern);
+///
+/// let mut events = series.iter();
+///
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 2).at(10, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 14).at(10, 0, 0, 0))));
+/// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 16).at(10, 0, 0, 0))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Weekly {
+    interval: i32,
+    weekdays: Weekdays,
+    at: Option<Time>,
+    week_start: Weekday,
+}
+
+impl Weekly {
+    /// Creates a new `Weekly` from an interval of weeks.
+    ///
+    /// The fallible version of this method is [`Weekly::try_new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is negative or zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::Weekly;
+    ///
+    /// let every_two_weeks = Weekly::new(2);
+    /// ```
+    pub fn new<I: ToSpan>(interval: I) -> Weekly {
+        Weekly::try_new(interval).expect("invalid weekly interval")
+    }
+
+    /// Creates a new `Weekly` from an interval of weeks.
+    ///
+    /// The panicking version of this method is [`Weekly::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if `interval` is negative or zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use recurring::pattern::Weekly;
+    ///
+    /// assert!(Weekly::try_new(1).is_ok());
+    /// assert!(Weekly::try_new(0).is_err());
+    /// assert!(Weekly::try_new(-1).is_err());
+    /// ```
+    pub fn try_new<I: ToSpan>(interval: I) -> Result<Weekly, Error> {
+        let span = interval.weeks();
+        if !span.is_positive() {
+            return Err(err!("interval must be positive but got {span}"));
+        }
+
+        Ok(Weekly {
+            interval: span.get_weeks(),
+            weekdays: Weekdays::default(),
+            at: None,
+            week_start: Weekday::Monday,
+        })
+    }
+
+    /// Limit the events to specific weekdays.
+    ///
+    /// This method can be called multiple times to add more weekdays. If it is never called,
+    /// events happen on the weekday of the series fixpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::Weekday;
+    /// use recurring::pattern::weekly;
+    ///
+    /// let every_week_on_monday_and_friday = weekly(1).on([Weekday::Monday, Weekday::Friday]);
+    /// ```
+    #[must_use]
+    pub fn on<I: IntoIterator<Item = Weekday>>(self, weekdays: I) -> Weekly {
+        weekdays.into_iter().fold(self, |weekly, weekday| {
+            weekly.weekday_i8(weekday.to_monday_one_offset())
+        })
+    }
+
+    #[inline]
+    fn weekday_i8(mut self, weekday: i8) -> Weekly {
+        self.weekdays
+            .try_insert(weekday)
+            .expect("weekday is out of bounds, please file a bug");
+        self
+    }
+
+    /// Sets the exact time of day for the weekly recurrence.
+    ///
+    /// If this is not set, events happen at the time of day of the series fixpoint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::time;
+    /// use recurring::pattern::weekly;
+    ///
+    /// let every_week_at_nine = weekly(1).at(time(9, 0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn at<T: Into<Time>>(mut self, time: T) -> Weekly {
+        self.at = Some(time.into());
+        self
+    }
+
+    /// Sets the first day of the week, which defaults to Monday.
+    ///
+    /// This determines which days belong to the same week and thus matters for intervals of more
+    /// than one week.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::{Weekday, date, time};
+    /// use recurring::{Event, Series, pattern::weekly};
+    ///
+    /// let pattern = weekly(2)
+    ///     .on([Weekday::Sunday, Weekday::Monday])
+    ///     .at(time(12, 0, 0, 0))
+    ///     .week_start(Weekday::Sunday);
+    ///
+    /// // 2025-01-01 is a Wednesday, so the first week ends on Saturday, 2025-01-04.
+    /// let series = Series::new(date(2025, 1, 1).at(0, 0, 0, 0).., pattern);
+    ///
+    /// let mut events = series.iter();
+    ///
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 12).at(12, 0, 0, 0))));
+    /// assert_eq!(events.next(), Some(Event::at(date(2025, 1, 13).at(12, 0, 0, 0))));
+    /// ```
+    #[must_use]
+    pub fn week_start(mut self, weekday: Weekday) -> Weekly {
+        self.week_start = weekday;
+        self
+    }
+
+    /// Returns the first day of the week containing `date`.
+    fn start_of_week(&self, date: Date) -> Option<Date> {
+        let days = date.weekday().since(self.week_start);
+        date.checked_sub(i64::from(days).days()).ok()
+    }
+
+    /// Returns the weekday set, which defaults to the weekday of the fixpoint.
+    fn weekdays(&self, fixpoint: DateTime) -> Weekdays {
+        if self.weekdays.is_empty() {
+            let mut weekdays = Weekdays::default();
+            weekdays
+                .try_insert(fixpoint.weekday().to_monday_one_offset())
+                .expect("weekday is out of bounds, please file a bug");
+            weekdays
+        } else {
+            self.weekdays
+        }
+    }
+
+    /// Returns the first date with events at or after `date`.
+    fn first_date_from(&self, mut date: Date, fixpoint: DateTime) -> Option<Date> {
+        let weekdays = self.weekdays(fixpoint);
+        let anchor = self.start_of_week(fixpoint.date())?;
+        let interval = i64::from(self.interval);
+
+        loop {
+            let week = self.start_of_week(date)?;
+            let weeks = i64::from(week.since(anchor).ok()?.get_days() / 7);
+
+            if weeks < 0 {
+                date = anchor;
+                continue;
+            }
+
+            let skip = (interval - weeks % interval) % interval;
+            if skip > 0 {
+                date = week.checked_add((skip * 7).days()).ok()?;
+                continue;
+            }
+
+            let end_of_week = week.checked_add(6.days()).ok()?;
+            while date <= end_of_week {
+                if weekdays.contains(date.weekday().to_monday_one_offset()) {
+                    return Some(date);
+                }
+
+                date = date.tomorrow().ok()?;
+            }
+
+            date = week.checked_add((interval * 7).days()).ok()?;
+        }
+    }
+
+    /// Returns the last date with events at or before `date`.
+    fn last_date_until(&self, mut date: Date, fixpoint: DateTime) -> Option<Date> {
+        let weekdays = self.weekdays(fixpoint);
+        let anchor = self.start_of_week(fixpoint.date())?;
+        let interval = i64::from(self.interval);
+
+        loop {
+            let week = self.start_of_week(date)?;
+            let weeks = i64::from(week.since(anchor).ok()?.get_days() / 7);
+
+            if weeks < 0 {
+                return None;
+            }
+
+            let skip = weeks % interval;
+            if skip > 0 {
+                date = week.checked_sub((skip * 7 - 6).days()).ok()?;
+                continue;
+            }
+
+            while date >= week {
+                if weekdays.contains(date.weekday().to_monday_one_offset()) {
+                    return Some(date);
+                }
+
+                date = date.yesterday().ok()?;
+            }
+
+            if weeks == 0 {
+                return None;
+            }
+
+            date = week.checked_sub((interval * 7 - 6).days()).ok()?;
+        }
+    }
+
+    /// Finds the first event within `range` after `instant` (or at `instant` if `inclusive` is
+    /// `true`).
+    fn find_next(
+        &self,
+        instant: DateTime,
+        inclusive: bool,
+        range: DateTimeRange,
+    ) -> Option<DateTime> {
+        let fixpoint = range.fixpoint();
+        let time = self.at.unwrap_or(fixpoint.time());
+        let mut date = instant.max(range.start).date();
+
+        loop {
+            date = self.first_date_from(date, fixpoint)?;
+            let next = date.to_datetime(time);
+            if next >= range.end {
+                return None;
+            }
+
+            if next >= range.start && (next > instant || (inclusive && next == instant)) {
+                return Some(next);
+            }
+
+            date = date.tomorrow().ok()?;
+        }
+    }
+}
+
+impl Pattern for Weekly {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.find_next(instant, false, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let fixpoint = range.fixpoint();
+        let time = self.at.unwrap_or(fixpoint.time());
+        let upper_bound = instant.min(range.end);
+        let mut date = upper_bound.date();
+
+        loop {
+            date = self.last_date_until(date, fixpoint)?;
+            let previous = date.to_datetime(time);
+            if previous < range.start {
+                return None;
+            }
+
+            if previous < upper_bound {
+                return Some(previous);
+            }
+
+            date = date.yesterday().ok()?;
+        }
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        let next = self.find_next(instant, true, range);
+        let previous = self.previous_before(instant, range);
+        pick_best(previous, next, |previous, next| {
+            closest_to(instant, previous, next)
+        })
+    }
+}
+
+impl private::Sealed for Weekly {}
@@ -72,10 +72,10 @@ impl DateTimeRange {
 
     /// Returns the (inclusive) fixpoint for relative recurrence patterns.
     ///
-    /// Unless [`DateTimeRange::with_fixpoint`] was called with a specific value, this returns the
-    /// same value as [`DateTimeRange::start`].
+    /// Unless the series was built with a specific [fixpoint][crate::series::SeriesWith::fixpoint],
+    /// this returns the same value as [`DateTimeRange::start`].
     #[inline]
-    pub(crate) fn fixpoint(&self) -> DateTime {
+    pub fn fixpoint(&self) -> DateTime {
         self.fixpoint.unwrap_or(self.start)
     }
 
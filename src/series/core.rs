@@ -75,7 +75,7 @@ where
     }
 
     #[inline]
-    fn get_event_unchecked(&self, start: DateTime) -> Option<Event> {
+    pub(crate) fn get_event_unchecked(&self, start: DateTime) -> Option<Event> {
         if self.event_duration.is_positive() {
             let end = start.checked_add(self.event_duration).ok()?;
             Some(Event::new_unchecked(start, Some(end)))
@@ -0,0 +1,103 @@
+use crate::pattern::Union;
+use crate::series::{Series, SeriesCore};
+use crate::{DateTimeRange, Event, Pattern};
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+use jiff::civil::DateTime;
+
+/// An iterator over the events of a [`Series`] with a [`Union`] pattern.
+///
+/// This struct is created by the [`.iter_merged()`][Series::iter_merged] method of a `Series`.
+/// See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct Merge<'a, P> {
+    core: &'a SeriesCore<Union<P>>,
+    range: DateTimeRange,
+    front: Option<BinaryHeap<Reverse<(DateTime, usize)>>>,
+    back: Option<BinaryHeap<(DateTime, usize)>>,
+}
+
+impl<'a, P: Pattern> Merge<'a, P> {
+    pub(crate) fn new(series: &'a Series<Union<P>>) -> Merge<'a, P> {
+        Merge {
+            core: &series.core,
+            range: series.range,
+            front: None,
+            back: None,
+        }
+    }
+
+    fn patterns(&self) -> &'a [P] {
+        self.core.pattern().patterns()
+    }
+}
+
+impl<P> Iterator for Merge<'_, P>
+where
+    P: Pattern,
+{
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (patterns, range) = (self.patterns(), self.range);
+
+        // The heap is filled lazily with the first event of every pattern.
+        let heap = self.front.get_or_insert_with(|| {
+            patterns
+                .iter()
+                .enumerate()
+                .filter_map(|(index, pattern)| {
+                    let first = pattern.closest_to(range.start, range)?;
+                    Some(Reverse((first, index)))
+                })
+                .collect()
+        });
+
+        let Reverse((start, _)) = *heap.peek()?;
+
+        // Advance every pattern which produced this event to deduplicate events.
+        while let Some(&Reverse((next, index))) = heap.peek()
+            && next == start
+        {
+            heap.pop();
+            if let Some(next) = patterns[index].next_after(start, range) {
+                heap.push(Reverse((next, index)));
+            }
+        }
+
+        self.core.get_event_unchecked(start)
+    }
+}
+
+impl<P> DoubleEndedIterator for Merge<'_, P>
+where
+    P: Pattern,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (patterns, range) = (self.patterns(), self.range);
+
+        let heap = self.back.get_or_insert_with(|| {
+            patterns
+                .iter()
+                .enumerate()
+                .filter_map(|(index, pattern)| {
+                    let last = pattern.previous_before(range.end, range)?;
+                    Some((last, index))
+                })
+                .collect()
+        });
+
+        let (start, _) = *heap.peek()?;
+
+        while let Some(&(previous, index)) = heap.peek()
+            && previous == start
+        {
+            heap.pop();
+            if let Some(previous) = patterns[index].previous_before(start, range) {
+                heap.push((previous, index));
+            }
+        }
+
+        self.core.get_event_unchecked(start)
+    }
+}
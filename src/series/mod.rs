@@ -1,17 +1,20 @@
 //! A series of recurring events.
 mod core;
 mod iter;
+mod merge;
 mod range;
 mod split;
 mod with;
 
 use core::SeriesCore;
 pub use iter::Iter;
+pub use merge::Merge;
 pub use range::Range;
 pub use split::{SeriesSplit, SplitMode};
 pub use with::SeriesWith;
 
 use crate::error::Error;
+use crate::pattern::Union;
 use crate::{DateTimeRange, Event, Pattern, try_simplify_range};
 use ::core::ops::RangeBounds;
 use jiff::{Span, civil::DateTime};
@@ -611,6 +614,41 @@ where
     }
 }
 
+impl<P> Series<Union<P>>
+where
+    P: Pattern,
+{
+    /// Creates an iterator over the events in the series which merges the events of the
+    /// patterns in the [`Union`].
+    ///
+    /// This produces the same events as [`.iter()`][Series::iter], but keeps track of the next
+    /// event of every pattern in a heap. Each step only advances the patterns producing the current
+    /// event, which makes iterating over a union of many patterns much cheaper: each step takes
+    /// O(log n) time in the number of patterns instead of the O(n) time of `.iter()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use recurring::{Event, Series, pattern::{hourly, minutely, union}};
+    ///
+    /// let series = Series::new(date(2025, 1, 1).at(0, 0, 0, 0).., union([hourly(1), minutely(40)]));
+    ///
+    /// let mut iter = series.iter_merged();
+    ///
+    /// assert_eq!(iter.next(), Some(Event::at(date(2025, 1, 1).at(0, 0, 0, 0))));
+    /// assert_eq!(iter.next(), Some(Event::at(date(2025, 1, 1).at(0, 40, 0, 0))));
+    /// assert_eq!(iter.next(), Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0))));
+    /// assert_eq!(iter.next(), Some(Event::at(date(2025, 1, 1).at(1, 20, 0, 0))));
+    /// assert_eq!(iter.next(), Some(Event::at(date(2025, 1, 1).at(2, 0, 0, 0))));
+    /// assert_eq!(iter.next(), Some(Event::at(date(2025, 1, 1).at(2, 40, 0, 0))));
+    /// ```
+    #[inline]
+    pub fn iter_merged(&self) -> Merge<'_, P> {
+        Merge::new(self)
+    }
+}
+
 impl<'a, P> IntoIterator for &'a Series<P>
 where
     P: Pattern,
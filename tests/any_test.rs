@@ -0,0 +1,171 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use jiff::ToSpan;
+use jiff::civil::{DateTime, Weekday, date, time};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{
+    AnyPattern, BuiltinPattern, Cron, check_pattern, cron, daily, explicit, hourly, shift, union,
+    weekly,
+};
+use recurring::{Combine, DateTimeRange, Event, Series};
+
+fn patterns() -> Vec<(AnyPattern, BuiltinPattern)> {
+    let noon = || cron().hour(12).minute(0).second(0);
+
+    vec![
+        (AnyPattern::new(hourly(5)), hourly(5).into()),
+        (AnyPattern::new(daily(2)), daily(2).into()),
+        (AnyPattern::new(noon()), noon().into()),
+        (
+            AnyPattern::new(daily(1).and(noon()).and(hourly(7))),
+            daily(1).and(noon()).and(hourly(7)).into(),
+        ),
+        (
+            AnyPattern::new(hourly(3).and(AnyPattern::new(noon()))),
+            BuiltinPattern::combined(hourly(3), noon()),
+        ),
+        (
+            AnyPattern::new(union([noon(), cron().hour(18).minute(30).second(0)])),
+            union([noon(), cron().hour(18).minute(30).second(0)]).into(),
+        ),
+        (
+            AnyPattern::new(hourly(5).and(noon())),
+            BuiltinPattern::union([BuiltinPattern::from(hourly(5)), noon().into()]),
+        ),
+        (
+            AnyPattern::new(hourly(1).intersect(noon())),
+            hourly(1).intersect(noon()).into(),
+        ),
+        (
+            AnyPattern::new(hourly(1).except(cron().hours(1..=11))),
+            BuiltinPattern::except(hourly(1), cron().hours(1..=11)),
+        ),
+        (
+            AnyPattern::new(explicit([
+                date(2025, 1, 3).at(9, 0, 0, 0),
+                date(2025, 1, 2).at(17, 0, 0, 0),
+            ])),
+            explicit([
+                date(2025, 1, 3).at(9, 0, 0, 0),
+                date(2025, 1, 2).at(17, 0, 0, 0),
+            ])
+            .into(),
+        ),
+        (
+            AnyPattern::new(shift(noon(), 90.minutes())),
+            BuiltinPattern::shift(noon(), 90.minutes()),
+        ),
+        (
+            AnyPattern::new(weekly(1).on([Weekday::Friday]).at(time(8, 0, 0, 0))),
+            weekly(1).on([Weekday::Friday]).at(time(8, 0, 0, 0)).into(),
+        ),
+    ]
+}
+
+#[test]
+fn any_pattern() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let end = date(2025, 2, 1).at(0, 0, 0, 0);
+
+    for (any, builtin) in patterns() {
+        assert_eq!(
+            series_take(start..end, any.clone(), 10),
+            series_take(start..end, builtin.clone(), 10)
+        );
+        assert_eq!(
+            series_take_rev(start..end, any, 10),
+            series_take_rev(start..end, builtin, 10)
+        );
+    }
+
+    let series: Vec<Series<AnyPattern>> = patterns()
+        .into_iter()
+        .map(|(any, _)| Series::new(start..end, any))
+        .collect();
+
+    let firsts: Vec<Option<Event>> = series.iter().map(Series::first).collect();
+    assert_eq!(
+        firsts,
+        [
+            Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(12, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(12, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(12, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(12, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 2).at(17, 0, 0, 0))),
+            Some(Event::at(date(2025, 1, 1).at(13, 30, 0, 0))),
+            Some(Event::at(date(2025, 1, 3).at(8, 0, 0, 0))),
+        ]
+    );
+}
+
+#[test]
+fn any_pattern_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 4).at(0, 0, 0, 0));
+    let instants = || (0..200).map(|i| start + jiff::Span::new().minutes(i * 29 - 300));
+
+    for (any, builtin) in patterns() {
+        check_pattern(&any, range, instants()).unwrap();
+        check_pattern(&builtin, range, instants()).unwrap();
+    }
+}
+
+#[test]
+fn builtin_pattern_variants() {
+    let cron: Cron = "0 12 * * *".parse().unwrap();
+    assert!(matches!(
+        BuiltinPattern::from(cron),
+        BuiltinPattern::Cron(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(hourly(1)),
+        BuiltinPattern::Interval(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(daily(1)),
+        BuiltinPattern::Daily(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(daily(1).and(hourly(1))),
+        BuiltinPattern::Combined(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(union([hourly(1), hourly(2)])),
+        BuiltinPattern::Union(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(daily(1).intersect(hourly(1))),
+        BuiltinPattern::Intersect(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(daily(1).except(hourly(2))),
+        BuiltinPattern::Except(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(explicit([date(2025, 1, 1).at(0, 0, 0, 0)])),
+        BuiltinPattern::Explicit(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(shift(hourly(1), 1.minute())),
+        BuiltinPattern::Shift(_)
+    ));
+    assert!(matches!(
+        BuiltinPattern::from(weekly(2)),
+        BuiltinPattern::Weekly(_)
+    ));
+
+    let pattern = AnyPattern::new(hourly(1));
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let clone = pattern.clone();
+    let instant = date(2025, 1, 1).at(0, 30, 0, 0);
+    assert_eq!(
+        recurring::Pattern::next_after(&clone, instant, range),
+        recurring::Pattern::next_after(&pattern, instant, range)
+    );
+}
@@ -1,9 +1,10 @@
 mod common;
 
 use common::{series_take, series_take_rev};
+use jiff::ToSpan;
 use jiff::civil::{DateTime, Weekday, date};
 use pretty_assertions::assert_eq;
-use recurring::pattern::{Cron, cron};
+use recurring::pattern::{Cron, CronField, CronMacro, CronMismatch, DayMatch, cron};
 use recurring::{DateTimeRange, Event, Pattern};
 
 #[test]
@@ -115,6 +116,27 @@ fn cron_next_after() {
     );
 }
 
+#[test]
+fn cron_out_of_range_instants() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 1, 3).at(0, 0, 0, 0);
+    let range = DateTimeRange::from(start..end);
+    let pattern = Cron::new().hour(12).minute(0).second(0);
+
+    assert_eq!(
+        pattern.next_after(date(2024, 12, 30).at(0, 0, 0, 0), range),
+        Some(date(2025, 1, 1).at(12, 0, 0, 0))
+    );
+    assert_eq!(
+        pattern.previous_before(date(2025, 1, 5).at(0, 0, 0, 0), range),
+        Some(date(2025, 1, 2).at(12, 0, 0, 0))
+    );
+    assert_eq!(
+        pattern.previous_before(DateTime::MAX, range),
+        Some(date(2025, 1, 2).at(12, 0, 0, 0))
+    );
+}
+
 #[test]
 fn cron_previous_before() {
     let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
@@ -210,3 +232,1433 @@ fn cron_step_by() {
         ]
     );
 }
+
+#[test]
+fn cron_parse() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let pattern = Cron::parse("*/20 9-17/4 * JAN,jul MON-FRI").unwrap();
+
+    assert_eq!(
+        series_take(start.., pattern, 7),
+        vec![
+            Event::at(date(2025, 1, 1).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(9, 20, 0, 0)),
+            Event::at(date(2025, 1, 1).at(9, 40, 0, 0)),
+            Event::at(date(2025, 1, 1).at(13, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(13, 20, 0, 0)),
+            Event::at(date(2025, 1, 1).at(13, 40, 0, 0)),
+            Event::at(date(2025, 1, 1).at(17, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_parse_seconds_and_years() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let pattern: Cron = "15,45 0 12 1 */6 * 2026-2030/2".parse().unwrap();
+
+    assert_eq!(
+        series_take(start.., pattern, 5),
+        vec![
+            Event::at(date(2026, 1, 1).at(12, 0, 15, 0)),
+            Event::at(date(2026, 1, 1).at(12, 0, 45, 0)),
+            Event::at(date(2026, 7, 1).at(12, 0, 15, 0)),
+            Event::at(date(2026, 7, 1).at(12, 0, 45, 0)),
+            Event::at(date(2028, 1, 1).at(12, 0, 15, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_parse_day_match() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let take = |expr| series_take(start.., Cron::parse(expr).unwrap(), 4);
+    let every_day = vec![
+        Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+        Event::at(date(2025, 1, 2).at(0, 0, 0, 0)),
+        Event::at(date(2025, 1, 3).at(0, 0, 0, 0)),
+        Event::at(date(2025, 1, 4).at(0, 0, 0, 0)),
+    ];
+
+    // Neither day field starts with `*`, so a day only needs to match one of them.
+    assert_eq!(take("0 0 1-31 * MON"), every_day);
+    assert_eq!(take("0 0 1 * 0-6"), every_day);
+    assert_eq!(
+        take("0 0 1,15 * MON"),
+        vec![
+            Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 6).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 13).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 15).at(0, 0, 0, 0)),
+        ]
+    );
+
+    // A day field starting with `*` requires a day to match both fields.
+    assert_eq!(
+        take("0 0 */2 * MON"),
+        vec![
+            Event::at(date(2025, 1, 13).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 27).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 3).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 17).at(0, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        Cron::parse("0 0 */2 * MON").unwrap(),
+        cron()
+            .days_step_by(1..=31, 2)
+            .weekday(Weekday::Monday)
+            .hour(0)
+            .minute(0)
+            .second(0)
+            .day_match(DayMatch::And)
+    );
+}
+
+#[test]
+fn cron_parse_sunday() {
+    let start = date(2025, 5, 1).at(0, 0, 0, 0);
+    let expected = vec![
+        Event::at(date(2025, 5, 2).at(0, 0, 0, 0)),
+        Event::at(date(2025, 5, 3).at(0, 0, 0, 0)),
+        Event::at(date(2025, 5, 4).at(0, 0, 0, 0)),
+        Event::at(date(2025, 5, 9).at(0, 0, 0, 0)),
+    ];
+
+    for expr in [
+        "0 0 * * 5-7",
+        "0 0 * * FRI-SUN",
+        "0 0 * * 0,5,6",
+        "0 0 * * fri-0",
+    ] {
+        assert_eq!(
+            series_take(start.., Cron::parse(expr).unwrap(), 4),
+            expected,
+            "{expr}"
+        );
+    }
+}
+
+#[test]
+fn cron_display() {
+    assert_eq!(cron().to_string(), "* * * * * *");
+    assert_eq!(cron().second(0).to_string(), "* * * * *");
+    assert_eq!(cron().seconds(0..60).to_string(), "* * * * * *");
+    assert_eq!(
+        cron().second(0).minute_step_by(5, 10).to_string(),
+        "5-55/10 * * * *"
+    );
+    assert_eq!(
+        cron().second(0).minute(0).hour_step_by(0, 2).to_string(),
+        "0 */2 * * *"
+    );
+    assert_eq!(
+        cron().second(0).minute(0).hours([0, 12]).to_string(),
+        "0 */12 * * *"
+    );
+    assert_eq!(
+        cron()
+            .second(0)
+            .minutes([1, 2, 3, 5, 7, 9, 30])
+            .days([1, 15])
+            .to_string(),
+        "1-3,5-9/2,30 * 1,15 * *"
+    );
+    assert_eq!(
+        cron()
+            .second(0)
+            .weekdays([Weekday::Monday, Weekday::Wednesday, Weekday::Friday])
+            .to_string(),
+        "* * * * 1-5/2"
+    );
+    assert_eq!(
+        cron().second(0).weekday(Weekday::Sunday).to_string(),
+        "* * * * 7"
+    );
+    assert_eq!(
+        cron().minute(0).years([2025, 2026, 2027]).to_string(),
+        "* 0 * * * * 2025-2027"
+    );
+}
+
+#[test]
+fn cron_display_roundtrip() {
+    let expressions = [
+        "* * * * *",
+        "*/15 9-17 * * 1-5",
+        "0 0 1,15 */3 *",
+        "30 4 * * 7",
+        "0-10/5 0 0 1 JAN *",
+        "0 0 0 29 2 * 2028-2040/4",
+    ];
+
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+
+    for expr in expressions {
+        let pattern = Cron::parse(expr).unwrap();
+        let reparsed = Cron::parse(&pattern.to_string()).unwrap();
+
+        assert_eq!(reparsed, pattern, "{expr}");
+        assert_eq!(reparsed.to_string(), pattern.to_string(), "{expr}");
+        assert_eq!(
+            series_take(start.., reparsed, 20),
+            series_take(start.., pattern, 20),
+            "{expr}"
+        );
+    }
+}
+
+#[test]
+fn cron_to_cron_string() {
+    let patterns = [
+        cron(),
+        cron().second(0),
+        cron()
+            .minute_step_by(0, 15)
+            .hours(9..=17)
+            .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1),
+        cron()
+            .second(0)
+            .minute(0)
+            .hour(0)
+            .days([1, 15])
+            .month_step_by(1, 3),
+        cron()
+            .seconds([0, 30])
+            .day(29)
+            .month(2)
+            .years([2028, 2032, 2036]),
+        cron().second(0).minute(0).hour(0).last_day().day(1),
+        cron()
+            .second(0)
+            .minute(0)
+            .hour(0)
+            .nth_weekday(2, Weekday::Tuesday),
+        cron().second(0).minute(0).hour(0).nearest_weekday(15),
+        cron()
+            .second(0)
+            .minute(0)
+            .hour(0)
+            .day(13)
+            .weekday(Weekday::Friday)
+            .day_match(DayMatch::Or),
+        cron().second(0).minute(0).hour(0).year(0),
+    ];
+
+    for pattern in patterns {
+        let expr = pattern.to_cron_string().unwrap();
+        assert_eq!(expr, pattern.to_string());
+        assert_eq!(Cron::parse(&expr).unwrap(), pattern, "{expr}");
+    }
+
+    let unrepresentable = [
+        cron().day(13).weekday(Weekday::Friday),
+        cron().nth_weekday(1, Weekday::Monday).last_day(),
+        cron().year(-5),
+        cron().millisecond(500),
+        cron().milliseconds([0, 500]),
+        cron().day_of_year(100),
+        cron().iso_week(1),
+        cron().hours(9..=10).exclude_hours([9, 10]),
+        cron().last_day().exclude_day(-1),
+        cron().year(2025).exclude_year(2025),
+    ];
+
+    for pattern in unrepresentable {
+        assert!(pattern.to_cron_string().is_err(), "{pattern}");
+    }
+}
+
+#[test]
+fn cron_eq() {
+    assert_eq!(cron(), cron().seconds(0..60));
+    assert_eq!(cron().hours([1, 2]), cron().hours([2, 1]).hour(2));
+    assert_eq!(cron().seed("a").hour(1), cron().seed("b").hour(1));
+    assert_eq!(cron().day(13), cron().day(13).day_match(DayMatch::Or));
+    assert_ne!(
+        cron().day(13).weekday(Weekday::Friday),
+        cron()
+            .day(13)
+            .weekday(Weekday::Friday)
+            .day_match(DayMatch::Or)
+    );
+    assert_ne!(cron().last_day(), cron().last_day().days(1..=31));
+    assert_ne!(cron().month(2).day(30), cron().month(2).day(31));
+    assert_ne!(cron().second(0), cron());
+    assert_ne!(cron().millisecond(0), cron().milliseconds([0, 500]));
+    assert_eq!(cron().millisecond(0), cron());
+}
+
+#[test]
+fn cron_day_match_or() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 3, 1).at(0, 0, 0, 0);
+    let pattern = Cron::parse("0 0 1,15 * MON").unwrap();
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 7),
+        vec![
+            Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 6).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 13).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 15).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 20).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 27).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 1).at(0, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series_take_rev(start..end, pattern, 5),
+        vec![
+            Event::at(date(2025, 2, 24).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 17).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 15).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 10).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 3).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_day_match_and() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let pattern = cron()
+        .day(13)
+        .weekday(Weekday::Friday)
+        .hour(0)
+        .minute(0)
+        .second(0);
+
+    assert_eq!(
+        series_take(start.., pattern.clone(), 3),
+        vec![
+            Event::at(date(2025, 6, 13).at(0, 0, 0, 0)),
+            Event::at(date(2026, 2, 13).at(0, 0, 0, 0)),
+            Event::at(date(2026, 3, 13).at(0, 0, 0, 0)),
+        ]
+    );
+
+    // `DayMatch::Or` behaves like `DayMatch::And` if only one of the fields is restricted.
+    let pattern = cron()
+        .weekday(Weekday::Friday)
+        .hour(0)
+        .minute(0)
+        .second(0)
+        .day_match(DayMatch::Or);
+
+    assert_eq!(
+        series_take(start.., pattern, 2),
+        vec![
+            Event::at(date(2025, 1, 3).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 10).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_weekday_resets_time() {
+    let start = date(2025, 5, 12).at(15, 30, 0, 0);
+    let pattern = cron().weekday(Weekday::Tuesday).minute(0).second(0);
+
+    assert_eq!(
+        series_take(start.., pattern.clone(), 2),
+        vec![
+            Event::at(date(2025, 5, 13).at(0, 0, 0, 0)),
+            Event::at(date(2025, 5, 13).at(1, 0, 0, 0)),
+        ]
+    );
+
+    let end = date(2025, 5, 14).at(15, 30, 0, 0);
+    assert_eq!(
+        series_take_rev(..end, pattern, 2),
+        vec![
+            Event::at(date(2025, 5, 13).at(23, 0, 0, 0)),
+            Event::at(date(2025, 5, 13).at(22, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_previous_before_skipped_year() {
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let pattern = cron().years([2020, 2030]).hour(12).minute(0).second(0);
+
+    assert_eq!(
+        pattern.previous_before(date(2025, 6, 15).at(6, 0, 0, 0), range),
+        Some(date(2020, 12, 31).at(12, 0, 0, 0))
+    );
+}
+
+#[test]
+fn cron_last_days() {
+    let start = date(2024, 1, 1).at(0, 0, 0, 0);
+    let end = date(2024, 5, 1).at(0, 0, 0, 0);
+    let pattern = cron().days([1, -1, -31]).hour(0).minute(0).second(0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 8),
+        vec![
+            Event::at(date(2024, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2024, 1, 31).at(0, 0, 0, 0)),
+            Event::at(date(2024, 2, 1).at(0, 0, 0, 0)),
+            Event::at(date(2024, 2, 29).at(0, 0, 0, 0)),
+            Event::at(date(2024, 3, 1).at(0, 0, 0, 0)),
+            Event::at(date(2024, 3, 31).at(0, 0, 0, 0)),
+            Event::at(date(2024, 4, 1).at(0, 0, 0, 0)),
+            Event::at(date(2024, 4, 30).at(0, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series_take_rev(start..end, pattern, 4),
+        vec![
+            Event::at(date(2024, 4, 30).at(0, 0, 0, 0)),
+            Event::at(date(2024, 4, 1).at(0, 0, 0, 0)),
+            Event::at(date(2024, 3, 31).at(0, 0, 0, 0)),
+            Event::at(date(2024, 3, 1).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_last_day_closest_to() {
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let pattern = cron().last_day().hour(12).minute(0).second(0);
+
+    assert_eq!(
+        pattern.closest_to(date(2025, 2, 14).at(0, 0, 0, 0), range),
+        Some(date(2025, 1, 31).at(12, 0, 0, 0))
+    );
+    assert_eq!(
+        pattern.closest_to(date(2025, 2, 15).at(0, 0, 0, 0), range),
+        Some(date(2025, 2, 28).at(12, 0, 0, 0))
+    );
+}
+
+#[test]
+fn cron_last_days_parse_and_display() {
+    let pattern = Cron::parse("0 0 1,L-2,L * *").unwrap();
+    assert_eq!(pattern.to_string(), "0 0 1,L-2,L * *");
+    assert_eq!(
+        cron()
+            .second(0)
+            .minute(0)
+            .hour(0)
+            .days([-1, -3])
+            .to_string(),
+        "0 0 L-2,L * *"
+    );
+
+    let start = date(2025, 2, 1).at(0, 0, 0, 0);
+    assert_eq!(
+        series_take(start.., pattern, 4),
+        vec![
+            Event::at(date(2025, 2, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 26).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 28).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 1).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_nth_weekday() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 5, 1).at(0, 0, 0, 0);
+    let pattern = cron()
+        .nth_weekday(2, Weekday::Tuesday)
+        .hour(10)
+        .minute(0)
+        .second(0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 4),
+        vec![
+            Event::at(date(2025, 1, 14).at(10, 0, 0, 0)),
+            Event::at(date(2025, 2, 11).at(10, 0, 0, 0)),
+            Event::at(date(2025, 3, 11).at(10, 0, 0, 0)),
+            Event::at(date(2025, 4, 8).at(10, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series_take_rev(start..end, pattern, 4),
+        vec![
+            Event::at(date(2025, 4, 8).at(10, 0, 0, 0)),
+            Event::at(date(2025, 3, 11).at(10, 0, 0, 0)),
+            Event::at(date(2025, 2, 11).at(10, 0, 0, 0)),
+            Event::at(date(2025, 1, 14).at(10, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_last_weekday() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 6, 1).at(0, 0, 0, 0);
+    let pattern = cron()
+        .last_weekday(Weekday::Friday)
+        .nth_weekday(-2, Weekday::Monday)
+        .hour(17)
+        .minute(0)
+        .second(0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 6),
+        vec![
+            Event::at(date(2025, 1, 20).at(17, 0, 0, 0)),
+            Event::at(date(2025, 1, 31).at(17, 0, 0, 0)),
+            Event::at(date(2025, 2, 17).at(17, 0, 0, 0)),
+            Event::at(date(2025, 2, 28).at(17, 0, 0, 0)),
+            Event::at(date(2025, 3, 24).at(17, 0, 0, 0)),
+            Event::at(date(2025, 3, 28).at(17, 0, 0, 0)),
+        ]
+    );
+
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    assert_eq!(
+        pattern.closest_to(date(2025, 5, 26).at(0, 0, 0, 0), range),
+        Some(date(2025, 5, 30).at(17, 0, 0, 0))
+    );
+    assert_eq!(
+        pattern.closest_to(date(2025, 5, 23).at(0, 0, 0, 0), range),
+        Some(date(2025, 5, 19).at(17, 0, 0, 0))
+    );
+}
+
+#[test]
+fn cron_nth_weekday_parse_and_display() {
+    let pattern = Cron::parse("0 10 * * TUE#2").unwrap();
+    assert_eq!(pattern.to_string(), "0 10 * * 2#2");
+    assert_eq!(
+        Cron::parse("0 17 * * 5L").unwrap().to_string(),
+        "0 17 * * 5L"
+    );
+    assert_eq!(
+        Cron::parse("0 17 * * 0#-2,SUNL").unwrap().to_string(),
+        "0 17 * * 7L,7#-2"
+    );
+
+    // Classic cron semantics: the 1st of the month or the second Tuesday.
+    let pattern = Cron::parse("0 10 1 * 2#2").unwrap();
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    assert_eq!(
+        series_take(start.., pattern, 4),
+        vec![
+            Event::at(date(2025, 1, 1).at(10, 0, 0, 0)),
+            Event::at(date(2025, 1, 14).at(10, 0, 0, 0)),
+            Event::at(date(2025, 2, 1).at(10, 0, 0, 0)),
+            Event::at(date(2025, 2, 11).at(10, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_nearest_weekday() {
+    let start = date(2025, 1, 15).at(0, 0, 0, 0);
+    let end = date(2025, 7, 1).at(0, 0, 0, 0);
+    let pattern = cron().nearest_weekday(1).hour(0).minute(0).second(0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 5),
+        vec![
+            Event::at(date(2025, 2, 3).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 3).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 5, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 6, 2).at(0, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series_take_rev(start..end, pattern.clone(), 5),
+        vec![
+            Event::at(date(2025, 6, 2).at(0, 0, 0, 0)),
+            Event::at(date(2025, 5, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 3).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 3).at(0, 0, 0, 0)),
+        ]
+    );
+
+    // The nearest weekday lies after the day it is derived from.
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    assert_eq!(
+        pattern.next_after(date(2025, 3, 2).at(0, 0, 0, 0), range),
+        Some(date(2025, 3, 3).at(0, 0, 0, 0))
+    );
+}
+
+#[test]
+fn cron_nearest_weekday_month_end() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 9, 1).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(
+            start..end,
+            cron().nearest_weekday(31).hour(0).minute(0).second(0),
+            5
+        ),
+        vec![
+            Event::at(date(2025, 1, 31).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 31).at(0, 0, 0, 0)),
+            Event::at(date(2025, 5, 30).at(0, 0, 0, 0)),
+            Event::at(date(2025, 7, 31).at(0, 0, 0, 0)),
+            Event::at(date(2025, 8, 29).at(0, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series_take_rev(
+            start..end,
+            cron().nearest_weekday(-1).hour(0).minute(0).second(0),
+            5
+        ),
+        vec![
+            Event::at(date(2025, 8, 29).at(0, 0, 0, 0)),
+            Event::at(date(2025, 7, 31).at(0, 0, 0, 0)),
+            Event::at(date(2025, 6, 30).at(0, 0, 0, 0)),
+            Event::at(date(2025, 5, 30).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 30).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_nearest_weekday_parse_and_display() {
+    assert_eq!(
+        Cron::parse("0 9 15W * *").unwrap().to_string(),
+        "0 9 15W * *"
+    );
+    assert_eq!(
+        Cron::parse("0 0 1,LW,L-2W * *").unwrap().to_string(),
+        "0 0 1,LW,L-2W * *"
+    );
+    assert_eq!(
+        Cron::parse("0 0 1W,L * *").unwrap().to_string(),
+        "0 0 L,1W * *"
+    );
+
+    let start = date(2025, 2, 1).at(0, 0, 0, 0);
+    assert_eq!(
+        series_take(start.., Cron::parse("0 0 LW * *").unwrap(), 3),
+        vec![
+            Event::at(date(2025, 2, 28).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 31).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 30).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_parse_quartz() {
+    let cases = [
+        ("0 15 10 ? * 6L 2025-2030", "0 15 10 * * 5L 2025-2030"),
+        ("0 0 12 ? * MON-FRI", "0 12 * * 1-5"),
+        ("0 0 12 ? * 2-6", "0 12 * * 1-5"),
+        ("0 0 12 ? * 1,7", "0 12 * * 6,7"),
+        ("0 0 12 ? * L", "0 12 * * 6"),
+        ("0 15 10 ? * 6#3", "15 10 * * 5#3"),
+        ("0 0/5 14 * * ?", "*/5 14 * * *"),
+        ("30 15 10 L-2 * ?", "30 15 10 L-2 * *"),
+        ("0 0 9 LW * ?", "0 9 LW * *"),
+        ("0 0 9 15W JAN ? 2026", "0 0 9 15W 1 * 2026"),
+    ];
+
+    for (quartz, standard) in cases {
+        assert_eq!(
+            Cron::parse_quartz(quartz).unwrap().to_string(),
+            standard,
+            "{quartz}"
+        );
+    }
+
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    assert_eq!(
+        series_take(start.., Cron::parse_quartz("0 15 10 ? * 6#3").unwrap(), 3),
+        vec![
+            Event::at(date(2025, 1, 17).at(10, 15, 0, 0)),
+            Event::at(date(2025, 2, 21).at(10, 15, 0, 0)),
+            Event::at(date(2025, 3, 21).at(10, 15, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_unsatisfiable() {
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let instant = date(2025, 1, 1).at(0, 0, 0, 0);
+
+    for pattern in [
+        cron().month(2).day(30),
+        cron().months([4, 6, 9, 11]).day(31),
+        cron().day(1).nth_weekday(2, Weekday::Monday),
+        cron().years(2025..=2027).month(2).day(29),
+        Cron::parse("0 0 30 2 *").unwrap(),
+    ] {
+        assert!(!pattern.is_satisfiable(), "{pattern}");
+        assert!(pattern.clone().validate().is_err(), "{pattern}");
+        assert_eq!(pattern.next_after(instant, range), None, "{pattern}");
+        assert_eq!(pattern.previous_before(instant, range), None, "{pattern}");
+        assert_eq!(pattern.closest_to(instant, range), None, "{pattern}");
+        assert_eq!(series_take(instant.., pattern, 1), vec![]);
+    }
+}
+
+#[test]
+fn cron_rare_satisfiable() {
+    let pattern = cron()
+        .month(2)
+        .day(29)
+        .weekday(Weekday::Friday)
+        .hour(0)
+        .minute(0)
+        .second(0);
+
+    assert!(pattern.is_satisfiable());
+
+    let start = date(2008, 3, 1).at(0, 0, 0, 0);
+    let end = date(2100, 1, 1).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 3),
+        vec![
+            Event::at(date(2036, 2, 29).at(0, 0, 0, 0)),
+            Event::at(date(2064, 2, 29).at(0, 0, 0, 0)),
+            Event::at(date(2092, 2, 29).at(0, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series_take_rev(
+            date(2000, 1, 1).at(0, 0, 0, 0)..date(2064, 1, 1).at(0, 0, 0, 0),
+            pattern,
+            3
+        ),
+        vec![
+            Event::at(date(2036, 2, 29).at(0, 0, 0, 0)),
+            Event::at(date(2008, 2, 29).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_macros() {
+    let cases = [
+        ("@yearly", CronMacro::Yearly, Cron::yearly(), "0 0 1 1 *"),
+        ("@annually", CronMacro::Yearly, Cron::yearly(), "0 0 1 1 *"),
+        ("@monthly", CronMacro::Monthly, Cron::monthly(), "0 0 1 * *"),
+        ("@weekly", CronMacro::Weekly, Cron::weekly(), "0 0 * * 7"),
+        ("@daily", CronMacro::Daily, Cron::daily(), "0 0 * * *"),
+        ("@midnight", CronMacro::Daily, Cron::daily(), "0 0 * * *"),
+        ("@HOURLY", CronMacro::Hourly, Cron::hourly(), "0 * * * *"),
+    ];
+
+    for (name, macro_, pattern, expr) in cases {
+        assert_eq!(name.parse::<CronMacro>().unwrap(), macro_);
+        assert_eq!(Cron::parse(name).unwrap().to_string(), expr);
+        assert_eq!(Cron::from(macro_).to_string(), expr);
+        assert_eq!(pattern.to_string(), expr);
+        assert_eq!(pattern.as_macro(), Some(macro_));
+        assert_eq!(Cron::parse(expr).unwrap().as_macro(), Some(macro_));
+    }
+
+    assert_eq!(CronMacro::Weekly.to_string(), "@weekly");
+    assert_eq!(
+        Cron::parse("0 0 * * 0").unwrap().as_macro(),
+        Some(CronMacro::Weekly)
+    );
+    assert_eq!(Cron::parse("0 0 * * 1").unwrap().as_macro(), None);
+    assert_eq!(Cron::parse("0 0 0 * * * 2025").unwrap().as_macro(), None);
+    assert_eq!(cron().as_macro(), None);
+    assert_eq!(Cron::daily().millisecond(500).as_macro(), None);
+    assert_eq!(Cron::daily().day_of_year(1).as_macro(), None);
+    assert_eq!(Cron::monthly().last_day().as_macro(), None);
+    assert_eq!(
+        Cron::daily().millisecond(0).as_macro(),
+        Some(CronMacro::Daily)
+    );
+    assert_eq!(
+        Cron::hourly().hours(0..24).as_macro(),
+        Some(CronMacro::Hourly)
+    );
+    assert_eq!(
+        Cron::monthly().day_match(DayMatch::Or).as_macro(),
+        Some(CronMacro::Monthly)
+    );
+
+    let start = date(2025, 1, 1).at(12, 0, 0, 0);
+    assert_eq!(
+        series_take(start.., Cron::weekly(), 2),
+        vec![
+            Event::at(date(2025, 1, 5).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 12).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_hashed() {
+    let minute = |seed: &str| {
+        Cron::parse_with_seed("H * * * *", seed)
+            .unwrap()
+            .to_string()
+    };
+
+    // Stable for the same seed and equal to the builder.
+    assert_eq!(minute("job-1"), minute("job-1"));
+    assert_eq!(
+        minute("job-1"),
+        cron()
+            .seed("job-1")
+            .hashed(CronField::Minute)
+            .second(0)
+            .to_string()
+    );
+
+    // Spread across the field for different seeds.
+    let mut minutes: Vec<_> = (0..100).map(|i| minute(&format!("job-{i}"))).collect();
+    minutes.sort();
+    minutes.dedup();
+    assert!(minutes.len() > 30, "{minutes:?}");
+
+    for i in 0..100 {
+        let seed = format!("job-{i}");
+        let pattern = Cron::parse_with_seed("H(0-29)/10 H(9-17) * * H(1-5)", &seed).unwrap();
+        let builder = cron()
+            .seed(&seed)
+            .hashed_step_by(CronField::Minute, 0..=29, 10)
+            .hashed_in(CronField::Hour, 9..=17)
+            .hashed_in(CronField::Weekday, 1..=5)
+            .second(0);
+        assert_eq!(pattern.to_string(), builder.to_string());
+
+        let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+        let first = pattern
+            .next_after(date(2025, 1, 5).at(23, 59, 59, 0), range)
+            .unwrap();
+        let events = series_take(first.., pattern, 3);
+
+        let minute = first.minute();
+        assert!(minute < 10, "{seed}: {minute}");
+        assert!((9..=17).contains(&first.hour()), "{seed}");
+        assert!(first.date() <= date(2025, 1, 10), "{seed}");
+        assert_eq!(
+            events,
+            vec![
+                Event::at(first),
+                Event::at(first.with().minute(minute + 10).build().unwrap()),
+                Event::at(first.with().minute(minute + 20).build().unwrap()),
+            ],
+            "{seed}"
+        );
+    }
+}
+
+#[test]
+fn cron_hashed_day() {
+    let mut days = Vec::new();
+
+    for i in 0..200 {
+        let seed = format!("job-{i}");
+        let pattern = Cron::parse_with_seed("0 0 H * *", &seed).unwrap();
+        assert_eq!(
+            pattern,
+            cron()
+                .seed(&seed)
+                .hashed(CronField::Day)
+                .hour(0)
+                .minute(0)
+                .second(0)
+        );
+
+        // Hashed days exist in every month.
+        assert!(pattern.clone().month(2).is_satisfiable(), "{seed}");
+
+        let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+        let first = pattern
+            .next_after(date(2025, 1, 31).at(0, 0, 0, 0), range)
+            .unwrap();
+        assert_eq!(first.month(), 2, "{seed}");
+        days.push(first.day());
+
+        let stepped = Cron::parse_with_seed("0 0 H/7 * *", &seed).unwrap();
+        assert!(
+            stepped.month(2).day_match(DayMatch::And).is_satisfiable(),
+            "{seed}"
+        );
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    assert_eq!(days.first(), Some(&1));
+    assert_eq!(days.last(), Some(&28));
+
+    // An explicit range may still include later days.
+    assert!(Cron::parse_with_seed("0 0 H(29-31) * *", "job").is_ok());
+}
+
+#[test]
+fn cron_hashed_macro_seed() {
+    for name in ["@yearly", "@monthly", "@weekly", "@daily", "@hourly"] {
+        let pattern = Cron::parse_with_seed(name, "job-1").unwrap();
+        assert_eq!(pattern, Cron::parse(name).unwrap(), "{name}");
+
+        // The seed is kept for hashed fields configured after parsing.
+        assert_eq!(
+            pattern.hashed(CronField::Month),
+            Cron::parse(name)
+                .unwrap()
+                .seed("job-1")
+                .hashed(CronField::Month),
+            "{name}"
+        );
+    }
+
+    assert_eq!(
+        Cron::parse_with_seed("@daily", "job-1")
+            .unwrap()
+            .hashed(CronField::Month),
+        Cron::parse_with_seed("0 0 * H *", "job-1").unwrap()
+    );
+}
+
+#[test]
+fn cron_steps_with_range() {
+    let pattern = cron()
+        .second(0)
+        .minutes_step_by(0..=30, 15)
+        .hours_step_by(9..=17, 2)
+        .days_step_by(1..=10, 3)
+        .months_step_by(3..=12, 3)
+        .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 2)
+        .day_match(DayMatch::Or);
+    assert_eq!(pattern.to_string(), "0-30/15 9-17/2 1-10/3 3-12/3 1-5/2");
+
+    let pattern = cron()
+        .seconds_step_by(10..=50, 20)
+        .years_step_by(2025..=2030, 2);
+    assert_eq!(pattern.to_string(), "10-50/20 * * * * * 2025-2029/2");
+}
+
+#[test]
+fn cron_exclusions() {
+    let cases = [
+        (
+            cron().second(0).minute(0).exclude_hour(12),
+            "0 0-11,13-23 * * *",
+        ),
+        (
+            cron().second(0).minute(0).hours(9..=17).exclude_hour(12),
+            "0 9-11,13-17 * * *",
+        ),
+        (
+            cron()
+                .second(0)
+                .minute(0)
+                .hour(0)
+                .exclude_weekdays([Weekday::Saturday, Weekday::Sunday]),
+            "0 0 * * 1-5",
+        ),
+        (
+            cron().second(0).minute(0).hour(0).exclude_days([1, 31]),
+            "0 0 2-30 * *",
+        ),
+        (
+            cron()
+                .second(0)
+                .minute(0)
+                .hour(0)
+                .days([1, -1, -2])
+                .exclude_day(-2),
+            "0 0 1,L * *",
+        ),
+        (
+            cron()
+                .second(0)
+                .minute(0)
+                .hour(0)
+                .last_day()
+                .exclude_day(15),
+            "0 0 L * *",
+        ),
+        (
+            cron().second(0).minute(0).hour(0).exclude_months(6..=8),
+            "0 0 * 1-5,9-12 *",
+        ),
+        (
+            cron()
+                .second(0)
+                .minute(0)
+                .hour(0)
+                .years(2025..=2027)
+                .exclude_year(2026),
+            "0 0 0 * * * 2025,2027",
+        ),
+        (
+            cron()
+                .seconds([0, 30])
+                .exclude_second(30)
+                .exclude_minutes(1..=58),
+            "*/59 * * * *",
+        ),
+    ];
+
+    for (pattern, expected) in cases {
+        assert_eq!(pattern.to_string(), expected);
+    }
+
+    let start = date(2025, 1, 1).at(11, 0, 0, 0);
+    assert_eq!(
+        series_take(
+            start..,
+            cron().second(0).minute(0).hours(11..=13).exclude_hour(12),
+            3
+        ),
+        vec![
+            Event::at(date(2025, 1, 1).at(11, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(13, 0, 0, 0)),
+            Event::at(date(2025, 1, 2).at(11, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_exclude_all() {
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let instant = date(2025, 1, 1).at(0, 0, 0, 0);
+
+    for pattern in [
+        cron().hours(9..=10).exclude_hours([9, 10]),
+        cron().exclude_hours([9, 10]).hours(9..=10),
+        cron().hour(1).exclude_hour(1),
+        cron().exclude_minutes(0..=59),
+        cron().exclude_weekdays([
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ]),
+        cron()
+            .nth_weekday(1, Weekday::Monday)
+            .exclude_weekday(Weekday::Monday),
+        cron().last_day().exclude_day(-1),
+        cron().exclude_days(1..=31),
+        cron().year(2025).exclude_year(2025),
+    ] {
+        assert!(!pattern.is_satisfiable(), "{pattern:?}");
+        assert!(pattern.clone().validate().is_err(), "{pattern:?}");
+        assert_eq!(pattern.next_after(instant, range), None, "{pattern:?}");
+        assert_eq!(pattern.previous_before(instant, range), None, "{pattern:?}");
+
+        // Cron expressions cannot contain empty fields, so the pattern is written as an
+        // expression which never matches either.
+        assert_eq!(pattern.to_string(), "0 0 30 2 *", "{pattern:?}");
+        assert!(pattern.to_cron_string().is_err(), "{pattern:?}");
+    }
+}
+
+#[test]
+fn cron_exclusions_order_independent() {
+    let cases = [
+        (
+            cron().exclude_hour(12).hours(9..=17).minute(0),
+            cron().hours(9..=17).minute(0).exclude_hour(12),
+            "0 9-11,13-17 * * *",
+        ),
+        (
+            cron().exclude_minutes([0, 30]).minutes_step_by(0..=45, 15),
+            cron().minutes_step_by(0..=45, 15).exclude_minutes([0, 30]),
+            "15,45 * * * *",
+        ),
+        (
+            cron().exclude_second(10).seconds([0, 10, 20]).minute(0),
+            cron().seconds([0, 10, 20]).minute(0).exclude_second(10),
+            "0,20 0 * * * *",
+        ),
+        (
+            cron().exclude_day(15).days([1, 15]).hour(0).minute(0),
+            cron().days([1, 15]).hour(0).minute(0).exclude_day(15),
+            "0 0 1 * *",
+        ),
+        (
+            cron().exclude_day(-2).days([-1, -2]).hour(0).minute(0),
+            cron().days([-1, -2]).hour(0).minute(0).exclude_day(-2),
+            "0 0 L * *",
+        ),
+        (
+            cron().exclude_month(2).months(1..=3).hour(0).minute(0),
+            cron().months(1..=3).hour(0).minute(0).exclude_month(2),
+            "0 0 * 1,3 *",
+        ),
+        (
+            cron()
+                .exclude_weekday(Weekday::Wednesday)
+                .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+                .hour(0)
+                .minute(0),
+            cron()
+                .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+                .hour(0)
+                .minute(0)
+                .exclude_weekday(Weekday::Wednesday),
+            "0 0 * * 1,2,4,5",
+        ),
+        (
+            cron()
+                .exclude_year(2026)
+                .years(2025..=2027)
+                .hour(0)
+                .minute(0),
+            cron()
+                .years(2025..=2027)
+                .hour(0)
+                .minute(0)
+                .exclude_year(2026),
+            "0 0 0 * * * 2025,2027",
+        ),
+    ];
+
+    for (excluded_first, excluded_last, expected) in cases {
+        let excluded_first = excluded_first.second(0);
+        let excluded_last = excluded_last.second(0);
+
+        assert_eq!(excluded_first, excluded_last, "{expected}");
+        assert_eq!(excluded_first.to_string(), expected);
+        assert_eq!(excluded_last.to_string(), expected);
+
+        let start = date(2025, 1, 1).at(0, 0, 0, 0);
+        assert_eq!(
+            series_take(start.., excluded_first, 10),
+            series_take(start.., excluded_last, 10),
+            "{expected}"
+        );
+    }
+}
+
+#[test]
+fn cron_exclude_days_from_other_end() {
+    // Excluding the last day of the month also applies if days are not limited otherwise.
+    let pattern = cron().exclude_day(-1).hour(0).minute(0).second(0);
+    let start = date(2025, 1, 30).at(0, 0, 0, 0);
+    assert_eq!(
+        series_take(start.., pattern.clone(), 3),
+        vec![
+            Event::at(date(2025, 1, 30).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 2).at(0, 0, 0, 0)),
+        ]
+    );
+    assert!(!pattern.matches(date(2025, 2, 28).at(0, 0, 0, 0)));
+    assert!(pattern.matches(date(2024, 2, 28).at(0, 0, 0, 0)));
+    assert!(pattern.to_cron_string().is_err());
+
+    // Excluding the 31st also applies to days counted from the end of the month.
+    let pattern = cron()
+        .last_day()
+        .exclude_day(31)
+        .hour(0)
+        .minute(0)
+        .second(0);
+    assert_eq!(
+        series_take(start.., pattern.clone(), 2),
+        vec![
+            Event::at(date(2025, 2, 28).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 30).at(0, 0, 0, 0)),
+        ]
+    );
+    assert!(pattern.to_cron_string().is_err());
+
+    // Excluded weekdays also apply to nth weekdays.
+    let pattern = cron()
+        .nth_weekday(1, Weekday::Monday)
+        .nth_weekday(1, Weekday::Tuesday)
+        .exclude_weekday(Weekday::Monday)
+        .hour(0)
+        .minute(0)
+        .second(0);
+    assert_eq!(pattern.to_string(), "0 0 * * 2#1");
+    assert_eq!(pattern.to_cron_string().unwrap(), "0 0 * * 2#1");
+    assert_eq!(
+        series_take(start.., pattern, 2),
+        vec![
+            Event::at(date(2025, 2, 4).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 4).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_milliseconds() {
+    let pattern = cron().milliseconds([250, 750]);
+    let start = date(2025, 1, 1).at(12, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start.., pattern.clone(), 3),
+        vec![
+            Event::at(date(2025, 1, 1).at(12, 0, 0, 250_000_000)),
+            Event::at(date(2025, 1, 1).at(12, 0, 0, 750_000_000)),
+            Event::at(date(2025, 1, 1).at(12, 0, 1, 250_000_000)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(..start, pattern.clone(), 2),
+        vec![
+            Event::at(date(2025, 1, 1).at(11, 59, 59, 750_000_000)),
+            Event::at(date(2025, 1, 1).at(11, 59, 59, 250_000_000)),
+        ]
+    );
+
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let at = |millis: i32, nanos: i32| date(2025, 1, 1).at(12, 0, 0, millis * 1_000_000 + nanos);
+
+    assert_eq!(pattern.next_after(at(250, 0), range), Some(at(750, 0)));
+    assert_eq!(
+        pattern.next_after(at(249, 999_999), range),
+        Some(at(250, 0))
+    );
+    assert_eq!(pattern.next_after(at(250, 1), range), Some(at(750, 0)));
+    assert_eq!(pattern.previous_before(at(750, 0), range), Some(at(250, 0)));
+    assert_eq!(pattern.previous_before(at(750, 1), range), Some(at(750, 0)));
+    assert_eq!(pattern.previous_before(at(250, 1), range), Some(at(250, 0)));
+
+    assert_eq!(pattern.closest_to(at(250, 0), range), Some(at(250, 0)));
+    assert_eq!(pattern.closest_to(at(400, 0), range), Some(at(250, 0)));
+    assert_eq!(pattern.closest_to(at(500, 0), range), Some(at(750, 0)));
+    assert_eq!(pattern.closest_to(at(250, 1), range), Some(at(250, 0)));
+
+    // Without milliseconds, patterns tick at the start of the second.
+    assert_eq!(
+        cron().next_after(at(0, 1), range),
+        Some(date(2025, 1, 1).at(12, 0, 1, 0))
+    );
+    assert_eq!(cron().previous_before(at(0, 1), range), Some(at(0, 0)));
+    assert_eq!(
+        cron().closest_to(at(600, 0), range),
+        Some(date(2025, 1, 1).at(12, 0, 1, 0))
+    );
+
+    assert_eq!(
+        cron()
+            .milliseconds_step_by(0..=999, 100)
+            .second(0)
+            .to_string(),
+        "* * * * *"
+    );
+    assert_eq!(pattern.clone().second(0).minute(0).as_macro(), None);
+    assert_eq!(
+        cron().millisecond(0).second(0).minute(0).as_macro(),
+        Some(CronMacro::Hourly)
+    );
+}
+
+#[test]
+fn cron_days_of_year() {
+    let pattern = cron()
+        .days_of_year([1, 100, 366])
+        .hour(0)
+        .minute(0)
+        .second(0);
+    let start = date(2024, 1, 2).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start.., pattern.clone(), 4),
+        vec![
+            Event::at(date(2024, 4, 9).at(0, 0, 0, 0)),
+            Event::at(date(2024, 12, 31).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 10).at(0, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(..start, pattern, 3),
+        vec![
+            Event::at(date(2024, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2023, 4, 10).at(0, 0, 0, 0)),
+            Event::at(date(2023, 1, 1).at(0, 0, 0, 0)),
+        ]
+    );
+
+    // Day 366 only exists in leap years.
+    let pattern = cron().day_of_year(366).hour(0).minute(0).second(0);
+    assert_eq!(
+        series_take(date(2025, 1, 1).at(0, 0, 0, 0).., pattern, 2),
+        vec![
+            Event::at(date(2028, 12, 31).at(0, 0, 0, 0)),
+            Event::at(date(2032, 12, 31).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_iso_weeks() {
+    let pattern = cron()
+        .iso_weeks([1, 27])
+        .weekday(Weekday::Monday)
+        .hour(0)
+        .minute(0)
+        .second(0);
+    let start = date(2024, 12, 1).at(0, 0, 0, 0);
+
+    // The first ISO week of 2025 starts in December 2024.
+    assert_eq!(
+        series_take(start.., pattern.clone(), 3),
+        vec![
+            Event::at(date(2024, 12, 30).at(0, 0, 0, 0)),
+            Event::at(date(2025, 6, 30).at(0, 0, 0, 0)),
+            Event::at(date(2025, 12, 29).at(0, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(..start, pattern, 2),
+        vec![
+            Event::at(date(2024, 7, 1).at(0, 0, 0, 0)),
+            Event::at(date(2024, 1, 1).at(0, 0, 0, 0)),
+        ]
+    );
+
+    // Week 53 only exists in some years and may start in the previous year.
+    let pattern = cron().iso_week(53).day(1).hour(0).minute(0).second(0);
+    assert_eq!(
+        series_take(date(2021, 2, 1).at(0, 0, 0, 0).., pattern, 2),
+        vec![
+            Event::at(date(2027, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2033, 1, 1).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn cron_days_of_year_unsatisfiable() {
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let instant = date(2025, 1, 1).at(0, 0, 0, 0);
+
+    for pattern in [
+        cron().day_of_year(1).month(2),
+        cron().day_of_year(366).month(11),
+        cron().iso_week(1).month(6),
+        cron().iso_week(53).day_of_year(100),
+    ] {
+        assert!(!pattern.is_satisfiable(), "{pattern:?}");
+        assert_eq!(pattern.next_after(instant, range), None, "{pattern:?}");
+        assert_eq!(pattern.previous_before(instant, range), None, "{pattern:?}");
+    }
+
+    assert!(cron().day_of_year(366).is_satisfiable());
+    assert!(cron().iso_week(53).day(31).month(12).is_satisfiable());
+    assert_eq!(
+        cron().day_of_year(1).second(0).minute(0).hour(0).as_macro(),
+        None
+    );
+}
+
+#[test]
+fn cron_matches() {
+    let range = DateTimeRange::from(DateTime::MIN..DateTime::MAX);
+    let patterns = [
+        cron().minute_step_by(0, 15).hours(9..=17).second(0),
+        cron()
+            .days([1, 15])
+            .weekday(Weekday::Friday)
+            .hour(12)
+            .minute(0)
+            .second(0),
+        Cron::parse("0 0 L * 5#2").unwrap(),
+        cron().iso_week(1).millisecond(500).minute(0).second(30),
+    ];
+
+    for pattern in patterns {
+        let mut instant = date(2025, 1, 1).at(0, 0, 0, 0);
+
+        for _ in 0..10 {
+            let next = pattern.next_after(instant, range).unwrap();
+            assert!(pattern.matches(next), "{pattern:?} at {next}");
+            assert!(pattern.explain(next).is_empty(), "{pattern:?} at {next}");
+
+            let before = next.checked_sub(1.millisecond()).unwrap();
+            assert_eq!(
+                pattern.matches(before),
+                pattern.previous_before(next, range) == Some(before),
+                "{pattern:?} at {before}"
+            );
+            assert_eq!(pattern.matches(before), pattern.explain(before).is_empty());
+            instant = next;
+        }
+    }
+
+    assert!(!cron().matches(date(2025, 1, 1).at(0, 0, 0, 1)));
+}
+
+#[test]
+fn cron_explain() {
+    let explain = |pattern: &Cron, instant: DateTime| -> Vec<String> {
+        pattern
+            .explain(instant)
+            .iter()
+            .map(CronMismatch::to_string)
+            .collect()
+    };
+
+    let pattern = cron()
+        .months([1, 6])
+        .weekdays([
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ])
+        .hour(10)
+        .minutes_step_by(0..=59, 15)
+        .second(0);
+
+    assert_eq!(
+        explain(&pattern, date(2025, 3, 9).at(10, 5, 0, 0)),
+        [
+            "month 3 is not in 1,6",
+            "day of week 7 (Sunday) is not in 1-5",
+            "minute 5 is not in */15",
+        ]
+    );
+
+    let mismatch = &pattern.explain(date(2025, 1, 6).at(11, 0, 0, 0))[0];
+    assert_eq!(mismatch.field(), "hour");
+    assert_eq!(mismatch.value(), "11");
+    assert_eq!(mismatch.expected(), "10");
+
+    // With `DayMatch::Or`, both day fields are reported only if neither matches.
+    let pattern = Cron::parse("0 0 1,15 * MON").unwrap();
+    assert_eq!(
+        explain(&pattern, date(2025, 1, 7).at(0, 0, 0, 0)),
+        [
+            "day of month 7 is not in 1,15",
+            "day of week 2 (Tuesday) is not in 1",
+        ]
+    );
+    assert!(explain(&pattern, date(2025, 1, 6).at(0, 0, 0, 0)).is_empty());
+    assert!(explain(&pattern, date(2025, 1, 15).at(0, 0, 0, 0)).is_empty());
+
+    let pattern = cron()
+        .years([2025, 2027])
+        .day_of_year(100)
+        .iso_week(15)
+        .milliseconds([250, 750]);
+    assert_eq!(
+        explain(&pattern, date(2026, 1, 1).at(0, 0, 0, 100_000_001)),
+        [
+            "year 2026 is not in 2025,2027",
+            "day of year 1 is not in 100",
+            "ISO week 1 is not in 15",
+            "millisecond 100.000001 is not in 250,750",
+        ]
+    );
+    assert_eq!(
+        explain(&cron(), date(2025, 1, 1).at(0, 0, 0, 5_000_000)),
+        ["millisecond 5 is not in 0"]
+    );
+}
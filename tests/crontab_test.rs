@@ -0,0 +1,128 @@
+use jiff::civil::{DateTime, date};
+use pretty_assertions::assert_eq;
+use recurring::Event;
+use recurring::crontab::Crontab;
+use recurring::pattern::Cron;
+
+const CRONTAB: &str = r#"
+# /etc/crontab: system-wide crontab
+
+SHELL=/bin/sh
+PATH = "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin"
+
+# m h dom mon dow user	command
+17 *	* * *	root    cd / && run-parts --report /etc/cron.hourly
+25 6	* * *	root	test -x /usr/sbin/anacron || ( cd / && run-parts --report /etc/cron.daily )
+MAILTO='ops@example.com'
+*/15 9-17 * * mon-fri  www-data  /usr/bin/php /var/www/cron.php  # every 15 minutes
+@weekly nobody  /usr/bin/cleanup --days=7 %stdin%
+"#;
+
+#[test]
+fn crontab_parse() {
+    let crontab = Crontab::parse(CRONTAB).unwrap();
+    let entries = crontab.entries();
+    assert_eq!(entries.len(), 4);
+
+    let expected = [
+        (
+            "17 * * * *",
+            "root",
+            "cd / && run-parts --report /etc/cron.hourly",
+            8,
+        ),
+        (
+            "25 6 * * *",
+            "root",
+            "test -x /usr/sbin/anacron || ( cd / && run-parts --report /etc/cron.daily )",
+            9,
+        ),
+        (
+            "*/15 9-17 * * 1-5",
+            "www-data",
+            "/usr/bin/php /var/www/cron.php  # every 15 minutes",
+            11,
+        ),
+        (
+            "0 0 * * 7",
+            "nobody",
+            "/usr/bin/cleanup --days=7 %stdin%",
+            12,
+        ),
+    ];
+
+    for (entry, (cron, user, command, line)) in entries.iter().zip(expected) {
+        assert_eq!(entry.cron().to_string(), cron);
+        assert_eq!(entry.user(), user);
+        assert_eq!(entry.command(), command);
+        assert_eq!(entry.line(), line);
+    }
+
+    let env = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    };
+
+    let path = "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin";
+    assert_eq!(
+        entries[0].env(),
+        env(&[("SHELL", "/bin/sh"), ("PATH", path)])
+    );
+    assert_eq!(
+        entries[3].env(),
+        env(&[
+            ("SHELL", "/bin/sh"),
+            ("PATH", path),
+            ("MAILTO", "ops@example.com")
+        ])
+    );
+    assert_eq!(crontab.env(), entries[3].env());
+
+    // Later assignments replace earlier ones.
+    let crontab = Crontab::parse("A=1\nB=2\nA=3\n").unwrap();
+    assert_eq!(crontab.env(), env(&[("B", "2"), ("A", "3")]));
+    assert!(crontab.entries().is_empty());
+}
+
+#[test]
+fn crontab_series() {
+    let crontab: Crontab = CRONTAB.parse().unwrap();
+    let start = date(2025, 1, 4).at(0, 0, 0, 0);
+
+    let events: Vec<Vec<Event>> = crontab
+        .entries()
+        .iter()
+        .map(|entry| entry.series(start).iter().take(2).collect())
+        .collect();
+
+    assert_eq!(
+        events,
+        [
+            vec![
+                Event::at(date(2025, 1, 4).at(0, 17, 0, 0)),
+                Event::at(date(2025, 1, 4).at(1, 17, 0, 0)),
+            ],
+            vec![
+                Event::at(date(2025, 1, 4).at(6, 25, 0, 0)),
+                Event::at(date(2025, 1, 5).at(6, 25, 0, 0)),
+            ],
+            vec![
+                Event::at(date(2025, 1, 6).at(9, 0, 0, 0)),
+                Event::at(date(2025, 1, 6).at(9, 15, 0, 0)),
+            ],
+            vec![
+                Event::at(date(2025, 1, 5).at(0, 0, 0, 0)),
+                Event::at(date(2025, 1, 12).at(0, 0, 0, 0)),
+            ],
+        ]
+    );
+
+    let entry = &crontab.entries()[0];
+    assert!(entry.try_series(DateTime::MAX).is_err());
+    assert_eq!(
+        entry.try_series(start).unwrap().pattern().to_string(),
+        Cron::parse("17 * * * *").unwrap().to_string()
+    );
+}
@@ -0,0 +1,181 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use jiff::ToSpan;
+use jiff::civil::{DateTime, date};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{CustomPattern, check_pattern, cron, custom, daily, hourly, minutely};
+use recurring::{Combine, DateTimeRange, Event, Pattern, Series};
+
+/// Ticks at a fixed list of sorted datetimes, like the high tides of a tide table.
+#[derive(Debug, Clone)]
+struct Table(Vec<DateTime>);
+
+impl CustomPattern for Table {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0
+            .iter()
+            .copied()
+            .find(|&date| date > instant && date >= range.start())
+            .filter(|&date| date < range.end())
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0
+            .iter()
+            .rev()
+            .copied()
+            .find(|&date| date < instant && date < range.end())
+            .filter(|&date| date >= range.start())
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.0
+            .iter()
+            .copied()
+            .filter(|&date| date >= range.start() && date < range.end())
+            .min_by_key(|&date| instant.duration_since(date).abs())
+    }
+}
+
+/// Like `Table`, but ignores the range.
+#[derive(Debug, Clone)]
+struct Unbounded(Table);
+
+impl CustomPattern for Unbounded {
+    fn next_after(&self, instant: DateTime, _: DateTimeRange) -> Option<DateTime> {
+        self.0
+            .next_after(instant, DateTimeRange::from(DateTime::MIN..DateTime::MAX))
+    }
+
+    fn previous_before(&self, instant: DateTime, _: DateTimeRange) -> Option<DateTime> {
+        self.0
+            .previous_before(instant, DateTimeRange::from(DateTime::MIN..DateTime::MAX))
+    }
+
+    fn closest_to(&self, instant: DateTime, _: DateTimeRange) -> Option<DateTime> {
+        self.0
+            .closest_to(instant, DateTimeRange::from(DateTime::MIN..DateTime::MAX))
+    }
+}
+
+fn tides() -> Table {
+    Table(vec![
+        date(2025, 1, 1).at(4, 12, 0, 0),
+        date(2025, 1, 1).at(16, 37, 0, 0),
+        date(2025, 1, 2).at(5, 1, 0, 0),
+        date(2025, 1, 2).at(17, 24, 0, 0),
+    ])
+}
+
+/// Instants before, within and after `range`, hitting events of most patterns exactly.
+fn instants(range: DateTimeRange) -> impl Iterator<Item = DateTime> {
+    let start = range.start() - 2.hours();
+    (0..)
+        .map(move |i| start + (i * 7).minutes())
+        .take_while(move |&instant| instant <= range.end() + 2.hours())
+}
+
+#[test]
+fn custom_series() {
+    let start = date(2025, 1, 1).at(6, 0, 0, 0);
+    let pattern = custom(tides());
+
+    assert_eq!(
+        series_take(start.., pattern.clone(), 5),
+        vec![
+            Event::at(date(2025, 1, 1).at(16, 37, 0, 0)),
+            Event::at(date(2025, 1, 2).at(5, 1, 0, 0)),
+            Event::at(date(2025, 1, 2).at(17, 24, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..date(2025, 1, 2).at(12, 0, 0, 0), pattern.clone(), 5),
+        vec![
+            Event::at(date(2025, 1, 2).at(5, 1, 0, 0)),
+            Event::at(date(2025, 1, 1).at(16, 37, 0, 0)),
+        ]
+    );
+    assert_eq!(pattern.get().0.len(), 4);
+
+    let combined = pattern.and(cron().hour(12).minute(0).second(0));
+    assert_eq!(
+        series_take(start.., combined, 4),
+        vec![
+            Event::at(date(2025, 1, 1).at(12, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(16, 37, 0, 0)),
+            Event::at(date(2025, 1, 2).at(5, 1, 0, 0)),
+            Event::at(date(2025, 1, 2).at(12, 0, 0, 0)),
+        ]
+    );
+
+    let mut series = Series::new(start.., custom(tides()));
+    let tail = series.split_off(date(2025, 1, 2).at(0, 0, 0, 0)).unwrap();
+    assert_eq!(series.iter().count(), 1);
+    assert_eq!(tail.iter().count(), 2);
+}
+
+#[test]
+fn check_pattern_custom() {
+    let range =
+        DateTimeRange::from(date(2025, 1, 1).at(6, 0, 0, 0)..date(2025, 1, 2).at(12, 0, 0, 0));
+
+    check_pattern(&custom(tides()), range, instants(range)).unwrap();
+    check_pattern(&custom(Table(Vec::new())), range, instants(range)).unwrap();
+
+    let err = check_pattern(&custom(Unbounded(tides())), range, instants(range)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "next_after(2025-01-01T04:00:00) returned 2025-01-01T04:12:00 which is not after the \
+         instant within the range 2025-01-01T06:00:00..2025-01-02T12:00:00"
+    );
+}
+
+#[test]
+fn check_pattern_builtin() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+
+    for range in [
+        DateTimeRange::from(start..start + 2.days()),
+        DateTimeRange::from(start + 30.minutes().seconds(10)..start + 1.day().hours(3)),
+    ] {
+        check_pattern(&hourly(1), range, instants(range)).unwrap();
+        check_pattern(&minutely(45), range, instants(range)).unwrap();
+        check_pattern(&daily(1), range, instants(range)).unwrap();
+        check_pattern(
+            &cron().minute_step_by(0, 20).second(0),
+            range,
+            instants(range),
+        )
+        .unwrap();
+        check_pattern(
+            &cron().hours([1, 13]).minute(3).second(0),
+            range,
+            instants(range),
+        )
+        .unwrap();
+        check_pattern(&cron().year(2024), range, instants(range)).unwrap();
+        check_pattern(
+            &hourly(5).and(cron().minute(30).second(0)),
+            range,
+            instants(range),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn cron_outside_of_range() {
+    let range =
+        DateTimeRange::from(date(2025, 1, 1).at(12, 30, 0, 0)..date(2025, 1, 1).at(18, 30, 0, 0));
+    let pattern = cron().minute(0).second(0);
+
+    assert_eq!(
+        pattern.next_after(date(2025, 1, 1).at(11, 59, 0, 0), range),
+        Some(date(2025, 1, 1).at(13, 0, 0, 0))
+    );
+    assert_eq!(
+        pattern.previous_before(date(2025, 1, 1).at(19, 1, 0, 0), range),
+        Some(date(2025, 1, 1).at(18, 0, 0, 0))
+    );
+}
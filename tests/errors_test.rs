@@ -1,8 +1,13 @@
-use jiff::{ToSpan, civil::date};
+use core::ops::RangeInclusive;
+use jiff::{
+    ToSpan,
+    civil::{Weekday, date},
+};
 use pretty_assertions::assert_eq;
 use recurring::{
-    Event, Series,
-    pattern::{Cron, Interval, daily},
+    Combine, Event, Series,
+    crontab::Crontab,
+    pattern::{Cron, CronField, Interval, Weekly, daily, filter},
 };
 
 macro_rules! assert_err {
@@ -57,6 +62,39 @@ fn interval_errors() {
     );
 }
 
+#[test]
+fn weekly_errors() {
+    assert_err!(Weekly::try_new(0), "interval must be positive but got PT0S");
+    assert_err!(
+        Weekly::try_new(-2),
+        "interval must be positive but got -P2W"
+    );
+}
+
+#[test]
+fn filter_errors() {
+    assert_err!(
+        filter(daily(1), |_| true).try_budget(0),
+        "search budget must be positive",
+    );
+}
+
+#[test]
+fn intersect_errors() {
+    assert_err!(
+        daily(1).intersect(daily(2)).try_budget(0),
+        "search budget must be positive",
+    );
+}
+
+#[test]
+fn except_errors() {
+    assert_err!(
+        daily(1).except(daily(2)).try_budget(0),
+        "search budget must be positive",
+    );
+}
+
 #[test]
 fn cron_errors() {
     assert_err!(
@@ -64,8 +102,209 @@ fn cron_errors() {
         "parameter with value -10 is not in the required range of 0..=59",
     );
 
+    assert_err!(
+        Cron::new().try_millisecond(1000),
+        "parameter with value 1000 is not in the required range of 0..=999",
+    );
+
+    assert_err!(
+        Cron::new().try_day_of_year(367),
+        "parameter with value 367 is not in the required range of 1..=366",
+    );
+
+    assert_err!(
+        Cron::new().try_iso_weeks([1, 54]),
+        "parameter with value 54 is not in the required range of 1..=53",
+    );
+
+    assert_err!(Cron::new().try_day(0), "day must not be zero");
+
+    assert_err!(
+        Cron::new().try_days([1, -32]),
+        "parameter with value -32 is not in the required range of -31..=31",
+    );
+
+    assert_err!(
+        Cron::new().try_nearest_weekday(32),
+        "parameter with value 32 is not in the required range of -31..=31",
+    );
+
+    assert_err!(
+        Cron::new().try_nth_weekday(0, Weekday::Monday),
+        "nth weekday must not be zero",
+    );
+
+    assert_err!(
+        Cron::new().try_nth_weekday(6, Weekday::Monday),
+        "parameter with value 6 is not in the required range of -5..=5",
+    );
+
+    assert_err!(
+        Cron::new().month(2).day(30).validate(),
+        "cron pattern can never match",
+    );
+
+    assert_err!(
+        Cron::new().try_hashed_in(CronField::Hour, 0..=24),
+        "parameter with value 24 is not in the required range of 0..=23",
+    );
+
+    assert_err!(
+        Cron::new().try_hashed_step_by(CronField::Minute, 0..=59, 0),
+        "step must be greater than zero",
+    );
+
+    assert_err!(
+        Cron::new().try_hours_step_by(9..=24, 2),
+        "parameter with value 24 is not in the required range of 0..=23",
+    );
+
+    assert_err!(
+        Cron::new().try_minutes_step_by(RangeInclusive::new(30, 10), 5),
+        "range start 30 must be less than or equal to range end 10",
+    );
+
+    assert_err!(
+        Cron::new().try_days_step_by(1..=31, 0),
+        "step must be greater than zero",
+    );
+
+    assert_err!(
+        Cron::new().try_exclude_hours([12, 24]),
+        "parameter with value 24 is not in the required range of 0..=23",
+    );
+
+    assert_err!(Cron::new().try_exclude_day(0), "day must not be zero");
+
+    assert_err!(
+        Cron::new().try_exclude_day(-32),
+        "parameter with value -32 is not in the required range of -31..=31",
+    );
+
     assert_err!(
         Cron::new().try_years([2025, 10000]),
         "parameter with value 10000 is not in the required range of -9999..=9999",
     );
 }
+
+#[test]
+fn cron_parse_errors() {
+    assert_err!(
+        Cron::parse("* * * *"),
+        "cron expression must have 5 to 7 fields but got 4",
+    );
+
+    assert_err!(
+        Cron::parse("0 12 * * MON-FRI 2025 * *"),
+        "cron expression must have 5 to 7 fields but got 8",
+    );
+
+    assert_err!(Cron::parse("@reboot"), "unknown cron macro \"@reboot\"");
+
+    assert_err!(
+        Cron::parse("0,60 * * * *"),
+        "invalid minute field at position 3: parameter with value 60 is not in the required range of 0..=59",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 * FOO *"),
+        "invalid month field at position 7: invalid value \"FOO\"",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 0 * *"),
+        "invalid day-of-month field at position 5: parameter with value 0 is not in the required range of 1..=31",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 L-31 * *"),
+        "invalid day-of-month field at position 5: parameter with value 31 is not in the required range of 0..=30",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 1,0W * *"),
+        "invalid day-of-month field at position 7: parameter with value 0 is not in the required range of 1..=31",
+    );
+
+    assert_err!(
+        Cron::parse("H(30-10) * * * *"),
+        "invalid minute field at position 1: range start 30 must be less than or equal to range end 10",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 0 * * * H"),
+        "invalid year field at position 13: hashed values are not supported",
+    );
+
+    assert_err!(
+        Cron::parse("0 */0 * * *"),
+        "invalid hour field at position 3: step must be greater than zero",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 * * FRI-MON"),
+        "invalid day-of-week field at position 9: range start 5 must be less than or equal to range end 1",
+    );
+
+    assert_err!(
+        Cron::parse("0 0 * * 1,MON#6"),
+        "invalid day-of-week field at position 11: parameter with value 6 is not in the required range of -5..=5",
+    );
+}
+
+#[test]
+fn cron_parse_quartz_errors() {
+    assert_err!(
+        Cron::parse_quartz("0 12 * * ?"),
+        "quartz cron expression must have 6 or 7 fields but got 5",
+    );
+
+    assert_err!(
+        Cron::parse_quartz("0 0 12 * * MON"),
+        "exactly one of the day-of-month and day-of-week fields must be \"?\"",
+    );
+
+    assert_err!(
+        Cron::parse_quartz("0 0 12 ? * ?"),
+        "exactly one of the day-of-month and day-of-week fields must be \"?\"",
+    );
+
+    assert_err!(
+        Cron::parse_quartz("0 0 12 ? * 0"),
+        "invalid day-of-week field at position 12: parameter with value 0 is not in the required range of 1..=7",
+    );
+
+    assert_err!(
+        Cron::parse_quartz("? 0 12 1 * ?"),
+        "invalid second field at position 1: invalid value \"?\"",
+    );
+}
+
+#[test]
+fn crontab_errors() {
+    assert_err!(
+        Crontab::parse("# comment\n\n* * * *\n"),
+        "invalid crontab entry on line 3: expected a schedule followed by a user and a command",
+    );
+
+    assert_err!(
+        Crontab::parse("* * * * * root"),
+        "invalid crontab entry on line 1: expected a user and a command after the schedule",
+    );
+
+    assert_err!(
+        Crontab::parse("SHELL=/bin/sh\n@reboot root /bin/true"),
+        "invalid crontab entry on line 2: @reboot is not supported",
+    );
+
+    assert_err!(
+        Crontab::parse("@often root /bin/true"),
+        "invalid crontab entry on line 1: unknown cron macro \"@often\"",
+    );
+
+    assert_err!(
+        Crontab::parse("0 25 * * * root /bin/true"),
+        "invalid crontab entry on line 1: invalid hour field at position 3: \
+         parameter with value 25 is not in the required range of 0..=23",
+    );
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use jiff::{
+    ToSpan,
+    civil::{DateTime, Weekday, date},
+};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{check_pattern, cron, daily, hourly, minutely};
+use recurring::series::SplitMode;
+use recurring::{Combine, DateTimeRange, Event, Pattern, Series};
+
+fn workdays() -> impl Pattern {
+    let weekdays_at_nine = cron()
+        .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+        .hour(9)
+        .minute(0)
+        .second(0);
+    let holidays = cron().month(1).day(1).and(cron().month(12).days([25, 26]));
+    let days_off = cron()
+        .year(2025)
+        .month(1)
+        .day(2)
+        .and(cron().year(2025).month(1).day(9))
+        .and(cron().year(2025).month(12).day(24));
+
+    weekdays_at_nine.except(holidays).except(days_off)
+}
+
+#[test]
+fn except_workdays() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2026, 1, 1).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start..end, workdays(), 6),
+        vec![
+            Event::at(date(2025, 1, 3).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 6).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 7).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 8).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 10).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 13).at(9, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, workdays(), 3),
+        vec![
+            Event::at(date(2025, 12, 31).at(9, 0, 0, 0)),
+            Event::at(date(2025, 12, 30).at(9, 0, 0, 0)),
+            Event::at(date(2025, 12, 29).at(9, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn except_series_methods() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let mut series = Series::new(start..date(2026, 1, 1).at(0, 0, 0, 0), workdays());
+
+    let range: Vec<Event> = series
+        .range(date(2025, 12, 20).at(0, 0, 0, 0)..date(2025, 12, 30).at(0, 0, 0, 0))
+        .collect();
+    assert_eq!(
+        range,
+        vec![
+            Event::at(date(2025, 12, 22).at(9, 0, 0, 0)),
+            Event::at(date(2025, 12, 23).at(9, 0, 0, 0)),
+            Event::at(date(2025, 12, 29).at(9, 0, 0, 0)),
+        ]
+    );
+
+    assert_eq!(
+        series.get_closest_to(date(2025, 1, 9).at(9, 0, 0, 0)),
+        Some(Event::at(date(2025, 1, 10).at(9, 0, 0, 0)))
+    );
+    assert_eq!(series.get(date(2025, 1, 9).at(9, 0, 0, 0)), None);
+
+    let rest = series
+        .split_off((SplitMode::NextAfter, date(2025, 12, 23).at(12, 0, 0, 0)))
+        .unwrap();
+    assert_eq!(
+        rest.first(),
+        Some(Event::at(date(2025, 12, 29).at(9, 0, 0, 0)))
+    );
+    assert_eq!(
+        series.last(),
+        Some(Event::at(date(2025, 12, 23).at(9, 0, 0, 0)))
+    );
+}
+
+#[test]
+fn except_everything() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 2, 1).at(0, 0, 0, 0);
+    let pattern = hourly(2).except(hourly(1));
+
+    assert_eq!(series_take(start..end, pattern.clone(), 1), vec![]);
+    assert_eq!(series_take_rev(start..end, pattern, 1), vec![]);
+}
+
+#[test]
+fn except_budget() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let range = DateTimeRange::from(start..DateTime::MAX);
+
+    // The exclusion removes every event, so every search runs out of budget.
+    let pattern = daily(1).except(daily(1));
+    assert_eq!(series_take(start.., pattern.clone(), 1), vec![]);
+    assert_eq!(pattern.next_after(start, range), None);
+    assert_eq!(pattern.previous_before(DateTime::MAX, range), None);
+    assert_eq!(pattern.closest_to(start + 1.year(), range), None);
+
+    // 23:00 is the 24th event of the day.
+    let pattern = hourly(1).except(cron().hours(0..=22));
+    assert_eq!(
+        series_take(start.., pattern.clone().budget(24), 1),
+        vec![Event::at(date(2025, 1, 1).at(23, 0, 0, 0))]
+    );
+    assert_eq!(series_take(start.., pattern.budget(23), 1), vec![]);
+}
+
+#[test]
+fn except_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 4).at(0, 0, 0, 0));
+    let instants = || (-20..400).map(|i| start + (i * 11).minutes());
+
+    check_pattern(&hourly(1).except(hourly(3)), range, instants()).unwrap();
+    check_pattern(
+        &minutely(20).except(cron().hours([1, 7, 8])),
+        range,
+        instants(),
+    )
+    .unwrap();
+    check_pattern(&daily(1).except(daily(1)), range, instants()).unwrap();
+    check_pattern(&workdays(), range, instants()).unwrap();
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use common::{series_full, series_take};
+use jiff::{ToSpan, civil::date};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{Explicit, check_pattern, cron, explicit, hourly};
+use recurring::{Combine, DateTimeRange, Event};
+
+fn board_meetings() -> Explicit {
+    explicit([
+        date(2025, 11, 5).at(14, 0, 0, 0),
+        date(2025, 3, 12).at(9, 0, 0, 0),
+        date(2024, 12, 4).at(9, 0, 0, 0),
+        date(2025, 6, 18).at(9, 0, 0, 0),
+        date(2025, 3, 12).at(9, 0, 0, 0),
+    ])
+}
+
+#[test]
+fn explicit_series() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 11, 5).at(14, 0, 0, 0);
+
+    assert_eq!(
+        series_full(start..end, board_meetings()),
+        vec![
+            Event::at(date(2025, 3, 12).at(9, 0, 0, 0)),
+            Event::at(date(2025, 6, 18).at(9, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(series_full(start..end, Explicit::default()), vec![]);
+}
+
+#[test]
+fn explicit_modify() {
+    let mut pattern = board_meetings();
+    assert_eq!(pattern.dates().len(), 4);
+    assert!(pattern.contains(date(2025, 3, 12).at(9, 0, 0, 0)));
+
+    pattern.insert(date(2025, 1, 15).at(9, 0, 0, 0));
+    pattern.insert(date(2025, 1, 15).at(9, 0, 0, 0));
+    assert!(pattern.remove(date(2024, 12, 4).at(9, 0, 0, 0)));
+    assert!(!pattern.remove(date(2024, 12, 4).at(9, 0, 0, 0)));
+    pattern.extend([date(2025, 1, 1).at(0, 0, 0, 0)]);
+
+    assert_eq!(
+        pattern.dates(),
+        [
+            date(2025, 1, 1).at(0, 0, 0, 0),
+            date(2025, 1, 15).at(9, 0, 0, 0),
+            date(2025, 3, 12).at(9, 0, 0, 0),
+            date(2025, 6, 18).at(9, 0, 0, 0),
+            date(2025, 11, 5).at(14, 0, 0, 0),
+        ]
+    );
+}
+
+#[test]
+fn explicit_combined() {
+    let start = date(2025, 3, 1).at(0, 0, 0, 0);
+    let end = date(2026, 1, 1).at(0, 0, 0, 0);
+    let quarterly = cron()
+        .months([1, 4, 7, 10])
+        .day(1)
+        .hour(9)
+        .minute(0)
+        .second(0);
+
+    assert_eq!(
+        series_take(start..end, quarterly.clone().and(board_meetings()), 4),
+        vec![
+            Event::at(date(2025, 3, 12).at(9, 0, 0, 0)),
+            Event::at(date(2025, 4, 1).at(9, 0, 0, 0)),
+            Event::at(date(2025, 6, 18).at(9, 0, 0, 0)),
+            Event::at(date(2025, 7, 1).at(9, 0, 0, 0)),
+        ]
+    );
+
+    let skipped = explicit([date(2025, 7, 1).at(9, 0, 0, 0)]);
+    assert_eq!(
+        series_full(start..end, quarterly.except(skipped)),
+        vec![
+            Event::at(date(2025, 4, 1).at(9, 0, 0, 0)),
+            Event::at(date(2025, 10, 1).at(9, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn explicit_conformance() {
+    let start = date(2025, 3, 12).at(9, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 11, 5).at(14, 0, 0, 0));
+    let instants = || (-20..300).map(|i| start + (i * 1001).minutes());
+
+    check_pattern(&board_meetings(), range, instants()).unwrap();
+    check_pattern(&Explicit::default(), range, instants()).unwrap();
+    check_pattern(&hourly(1).and(board_meetings()), range, instants()).unwrap();
+}
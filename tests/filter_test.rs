@@ -0,0 +1,80 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use jiff::{
+    ToSpan,
+    civil::{DateTime, Weekday, date},
+};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{check_pattern, daily, filter, hourly, minutely};
+use recurring::{DateTimeRange, Event, Pattern, Series};
+
+#[test]
+fn filter_maintenance_calendar() {
+    let maintenance = [date(2025, 1, 2), date(2025, 1, 3), date(2025, 1, 6)];
+    let pattern = filter(daily(1), move |instant| {
+        !maintenance.contains(&instant.date())
+    });
+
+    let start = date(2025, 1, 1).at(8, 0, 0, 0);
+    let end = date(2025, 1, 7).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 10),
+        vec![
+            Event::at(date(2025, 1, 1).at(8, 0, 0, 0)),
+            Event::at(date(2025, 1, 4).at(8, 0, 0, 0)),
+            Event::at(date(2025, 1, 5).at(8, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, pattern, 10),
+        vec![
+            Event::at(date(2025, 1, 5).at(8, 0, 0, 0)),
+            Event::at(date(2025, 1, 4).at(8, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(8, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn filter_budget() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let range = DateTimeRange::from(start..DateTime::MAX);
+
+    // A predicate rejecting everything gives up instead of searching until `DateTime::MAX`.
+    let never = filter(minutely(1), |_| false);
+    assert_eq!(never.next_after(start, range), None);
+    assert_eq!(never.closest_to(start, range), None);
+    assert_eq!(Series::new(start.., never).last(), None);
+
+    // Sundays are more than 24 hourly events apart.
+    let sundays =
+        |budget| filter(hourly(1), |instant| instant.weekday() == Weekday::Sunday).budget(budget);
+    assert_eq!(sundays(24).next_after(start, range), None);
+    assert_eq!(
+        sundays(24 * 7).next_after(start, range),
+        Some(date(2025, 1, 5).at(0, 0, 0, 0))
+    );
+}
+
+#[test]
+fn filter_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 4).at(0, 0, 0, 0));
+    let instants = || (-20..400).map(|i| start + (i * 11).minutes());
+
+    check_pattern(
+        &filter(minutely(20), |instant| instant.hour() % 5 != 0),
+        range,
+        instants(),
+    )
+    .unwrap();
+    check_pattern(&filter(hourly(1), |_| false), range, instants()).unwrap();
+    check_pattern(
+        &filter(hourly(1), |instant| instant.day() == 2),
+        range,
+        instants(),
+    )
+    .unwrap();
+}
@@ -0,0 +1,102 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use jiff::{
+    ToSpan,
+    civil::{DateTime, Weekday, date},
+};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{check_pattern, cron, daily, hourly, minutely};
+use recurring::{Combine, DateTimeRange, Event, Pattern};
+
+#[test]
+fn intersect_business_hours() {
+    let business_hours = cron()
+        .weekdays_step_by(Weekday::Monday..=Weekday::Friday, 1)
+        .hours(9..=16);
+    let pattern = minutely(15).intersect(business_hours);
+
+    // 2025-01-03 is a Friday.
+    let start = date(2025, 1, 3).at(16, 20, 0, 0);
+    let end = date(2025, 1, 7).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 4),
+        vec![
+            Event::at(date(2025, 1, 3).at(16, 20, 0, 0)),
+            Event::at(date(2025, 1, 3).at(16, 35, 0, 0)),
+            Event::at(date(2025, 1, 3).at(16, 50, 0, 0)),
+            Event::at(date(2025, 1, 6).at(9, 5, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, pattern, 2),
+        vec![
+            Event::at(date(2025, 1, 6).at(16, 50, 0, 0)),
+            Event::at(date(2025, 1, 6).at(16, 35, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn intersect_budget() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let range = DateTimeRange::from(start..DateTime::MAX);
+
+    // The patterns never produce the same event, so every search runs out of budget.
+    let pattern = minutely(15).intersect(cron().second(30));
+    assert_eq!(series_take(start.., pattern.clone(), 1), vec![]);
+    assert_eq!(pattern.next_after(start, range), None);
+    assert_eq!(pattern.previous_before(start + 1.year(), range), None);
+    assert_eq!(pattern.closest_to(start + 1.year(), range), None);
+
+    // The search steps through 00:10, 01:10, ..., 11:10 and confirms 12:00 in the 14th step.
+    let noon = cron().hour(12).minute(0).second(0);
+    let pattern = minutely(15).intersect(cron().minute(10).second(0).and(noon));
+    assert_eq!(
+        series_take(start.., pattern.clone().budget(14), 1),
+        vec![Event::at(date(2025, 1, 1).at(12, 0, 0, 0))]
+    );
+    assert_eq!(series_take(start.., pattern.budget(13), 1), vec![]);
+}
+
+#[test]
+fn intersect_closest_to() {
+    let pattern = hourly(2).intersect(hourly(3));
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 2).at(0, 0, 0, 0));
+
+    let closest = |hour, minute| pattern.closest_to(date(2025, 1, 1).at(hour, minute, 0, 0), range);
+
+    assert_eq!(closest(2, 0), Some(date(2025, 1, 1).at(0, 0, 0, 0)));
+    assert_eq!(closest(3, 0), Some(date(2025, 1, 1).at(6, 0, 0, 0)));
+    assert_eq!(closest(6, 0), Some(date(2025, 1, 1).at(6, 0, 0, 0)));
+    assert_eq!(closest(20, 0), Some(date(2025, 1, 1).at(18, 0, 0, 0)));
+    assert_eq!(closest(23, 0), Some(date(2025, 1, 1).at(18, 0, 0, 0)));
+}
+
+#[test]
+fn intersect_disjoint() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 2, 1).at(0, 0, 0, 0);
+    let pattern = daily(1).intersect(cron().hour(12));
+
+    assert_eq!(series_take(start..end, pattern.clone(), 1), vec![]);
+    assert_eq!(series_take_rev(start..end, pattern, 1), vec![]);
+}
+
+#[test]
+fn intersect_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 4).at(0, 0, 0, 0));
+    let instants = || (-20..400).map(|i| start + (i * 11).minutes());
+
+    check_pattern(&hourly(2).intersect(hourly(3)), range, instants()).unwrap();
+    check_pattern(
+        &minutely(20).intersect(cron().hours([1, 7, 8]).minute(40)),
+        range,
+        instants(),
+    )
+    .unwrap();
+    check_pattern(&daily(1).intersect(cron().hour(12)), range, instants()).unwrap();
+}
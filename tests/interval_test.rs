@@ -115,6 +115,16 @@ fn interval_previous_before() {
         interval.previous_before(range.end(), range),
         Some(date(2025, 1, 2).at(23, 0, 0, 0))
     );
+
+    assert_eq!(
+        interval.previous_before(range.end() + 30.minutes(), range),
+        Some(date(2025, 1, 2).at(23, 0, 0, 0))
+    );
+
+    assert_eq!(
+        interval.previous_before(DateTime::MAX, range),
+        Some(date(2025, 1, 2).at(23, 0, 0, 0))
+    );
 }
 
 #[test]
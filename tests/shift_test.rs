@@ -0,0 +1,185 @@
+mod common;
+
+use common::{series_full, series_take, series_take_rev};
+use jiff::{
+    ToSpan,
+    civil::{date, time},
+};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{check_pattern, cron, daily, hourly, shift, weekly};
+use recurring::{Combine, DateTimeRange, Event, Pattern, Series};
+
+#[test]
+fn shift_reminders() {
+    let meetings = cron().hours([10, 15]).minute(0).second(0);
+    let start = date(2025, 1, 1).at(9, 0, 0, 0);
+    let end = date(2025, 1, 2).at(9, 0, 0, 0);
+
+    // The reminder for the 10:00 meeting on 2025-01-02 is within the range even though the
+    // meeting itself is not.
+    assert_eq!(
+        series_full(start..end, shift(meetings.clone(), -2.hours())),
+        vec![
+            Event::at(date(2025, 1, 1).at(13, 0, 0, 0)),
+            Event::at(date(2025, 1, 2).at(8, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_full(start..end, shift(meetings, 30.minutes())),
+        vec![
+            Event::at(date(2025, 1, 1).at(10, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(15, 30, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn shift_after_month_end() {
+    let month_end = cron().day(-1).hour(0).minute(0).second(0);
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 5, 1).at(0, 0, 0, 0);
+
+    // The end of December is before the series start, but its shifted event is not.
+    assert_eq!(
+        series_take(start..end, shift(month_end.clone(), 1.day()), 10),
+        vec![
+            Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 2, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 4, 1).at(0, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, shift(month_end, 1.day()), 2),
+        vec![
+            Event::at(date(2025, 4, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 3, 1).at(0, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn shift_across_start() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 1, 3).at(0, 0, 0, 0);
+    let late = cron().hour(23).minute(0).second(0);
+
+    assert_eq!(
+        series_full(start..end, shift(late.clone(), 2.hours())),
+        vec![
+            Event::at(date(2025, 1, 1).at(1, 0, 0, 0)),
+            Event::at(date(2025, 1, 2).at(1, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, shift(late.clone(), 2.hours()), 3),
+        vec![
+            Event::at(date(2025, 1, 2).at(1, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(1, 0, 0, 0)),
+        ]
+    );
+
+    let series = Series::new(start..end, shift(late, 2.hours()));
+    assert_eq!(
+        series.get_closest_to(start),
+        Some(Event::at(date(2025, 1, 1).at(1, 0, 0, 0)))
+    );
+
+    // Relative patterns are anchored to the moved range start, which is a Tuesday.
+    assert_eq!(
+        series_take(
+            start..end,
+            shift(daily(1).at(time(23, 0, 0, 0)), 2.hours()),
+            3
+        ),
+        vec![
+            Event::at(date(2025, 1, 1).at(1, 0, 0, 0)),
+            Event::at(date(2025, 1, 2).at(1, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take(
+            start..end,
+            shift(weekly(1).at(time(23, 0, 0, 0)), 2.hours()),
+            3
+        ),
+        vec![Event::at(date(2025, 1, 1).at(1, 0, 0, 0))]
+    );
+}
+
+#[test]
+fn shift_interval() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 1, 1).at(10, 0, 0, 0);
+
+    // With a negative offset, intervals stay anchored to the series fixpoint.
+    assert_eq!(
+        series_take(start..end, shift(hourly(4), -30.minutes()), 3),
+        vec![
+            Event::at(date(2025, 1, 1).at(3, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(7, 30, 0, 0)),
+        ]
+    );
+    // With a positive offset, they are anchored to the moved range start instead.
+    assert_eq!(
+        series_take(start..end, shift(hourly(4), 90.minutes()), 3),
+        vec![
+            Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(4, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(8, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take(start..end, hourly(4).offset(90.minutes()), 3),
+        vec![
+            Event::at(date(2025, 1, 1).at(1, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(5, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(9, 30, 0, 0)),
+        ]
+    );
+
+    let series = Series::new(start..end, shift(hourly(4).and(hourly(3)), -1.hour()));
+    assert_eq!(
+        series.get_closest_to(date(2025, 1, 1).at(3, 20, 0, 0)),
+        Some(Event::at(date(2025, 1, 1).at(3, 0, 0, 0)))
+    );
+}
+
+#[test]
+fn shift_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 4).at(0, 0, 0, 0));
+    let instants = || (-20..400).map(|i| start + (i * 11).minutes());
+
+    for offset in [-1.day(), -90.minutes(), 0.seconds(), 20.minutes(), 2.days()] {
+        check_pattern(&shift(hourly(3), offset), range, instants()).unwrap();
+        check_pattern(
+            &shift(cron().hours([0, 7]).minute(15), offset),
+            range,
+            instants(),
+        )
+        .unwrap();
+        check_pattern(
+            &shift(weekly(1).at(time(23, 0, 0, 0)), offset),
+            range,
+            instants(),
+        )
+        .unwrap();
+    }
+    check_pattern(&shift(cron().day(-1), 1.month()), range, instants()).unwrap();
+
+    // Shifting by months clamps days to the end of the month, e.g. January 31 moves to February 28.
+    let range = DateTimeRange::from(start..start + 400.days());
+    let instants = || (-20..2000).map(|i| start + (i * 317).minutes());
+    let month_end = cron().day(-1).hour(0).minute(0).second(0);
+
+    for offset in [1.month(), -1.month(), 1.year(), -2.months()] {
+        check_pattern(&shift(month_end.clone(), offset), range, instants()).unwrap();
+    }
+
+    let pattern = shift(month_end, 1.month());
+    assert_eq!(
+        pattern.previous_before(date(2025, 2, 28).at(8, 28, 0, 0), range),
+        Some(date(2025, 2, 28).at(0, 0, 0, 0))
+    );
+}
@@ -0,0 +1,157 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use core::cell::Cell;
+use jiff::{
+    ToSpan,
+    civil::{DateTime, date},
+};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{
+    Cron, CustomPattern, Daily, Union, check_pattern, cron, custom, hourly, minutely, union,
+};
+use recurring::{DateTimeRange, Event, Pattern, Series};
+use std::rc::Rc;
+
+/// Wraps a cron pattern and counts how often it is queried.
+#[derive(Debug, Clone)]
+struct Counted {
+    pattern: Cron,
+    calls: Rc<Cell<usize>>,
+}
+
+impl Counted {
+    fn count(&self) {
+        self.calls.set(self.calls.get() + 1);
+    }
+}
+
+impl CustomPattern for Counted {
+    fn next_after(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.count();
+        self.pattern.next_after(instant, range)
+    }
+
+    fn previous_before(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.count();
+        self.pattern.previous_before(instant, range)
+    }
+
+    fn closest_to(&self, instant: DateTime, range: DateTimeRange) -> Option<DateTime> {
+        self.count();
+        self.pattern.closest_to(instant, range)
+    }
+}
+
+#[test]
+fn union_series() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 1, 2).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start..end, union([hourly(4), minutely(90)]), 6),
+        vec![
+            Event::at(date(2025, 1, 1).at(0, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(1, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(3, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(4, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(4, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(6, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, union([hourly(4), minutely(90)]), 3),
+        vec![
+            Event::at(date(2025, 1, 1).at(22, 30, 0, 0)),
+            Event::at(date(2025, 1, 1).at(21, 0, 0, 0)),
+            Event::at(date(2025, 1, 1).at(20, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take(start..end, Union::<Daily>::default(), 1),
+        vec![]
+    );
+}
+
+#[test]
+fn union_iter_merged() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 3, 1).at(0, 0, 0, 0);
+
+    // Many overlapping cron rules, some of which produce the same events.
+    let pattern: Union<_> = (0..50i8)
+        .map(|i| cron().hour(i % 24).minute((i % 20) * 3).second(0))
+        .chain([
+            cron().minute(0).second(0),
+            cron().hour(13).minute(30).second(0),
+        ])
+        .collect();
+
+    let series = Series::new(start..end, pattern)
+        .with()
+        .event_duration(5.minutes())
+        .build()
+        .unwrap();
+
+    let expected: Vec<Event> = series.iter().collect();
+    let merged: Vec<Event> = series.iter_merged().collect();
+    assert_eq!(merged, expected);
+
+    let expected: Vec<Event> = series.iter().rev().take(100).collect();
+    let merged: Vec<Event> = series.iter_merged().rev().take(100).collect();
+    assert_eq!(merged, expected);
+
+    let empty = Series::new(start..end, Union::<Cron>::default());
+    assert_eq!(empty.iter_merged().next(), None);
+    assert_eq!(empty.iter_merged().next_back(), None);
+}
+
+#[test]
+fn union_iter_merged_queries() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let calls = Rc::new(Cell::new(0));
+
+    // 60 hourly patterns, one for every minute of the hour.
+    let pattern: Union<_> = (0..60)
+        .map(|minute| {
+            custom(Counted {
+                pattern: cron().minute(minute).second(0),
+                calls: calls.clone(),
+            })
+        })
+        .collect();
+
+    let series = Series::new(start.., pattern);
+
+    let expected: Vec<Event> = series.iter().take(600).collect();
+    let iter_calls = calls.replace(0);
+
+    let merged: Vec<Event> = series.iter_merged().take(600).collect();
+    let merged_calls = calls.replace(0);
+
+    assert_eq!(merged, expected);
+    // `.iter()` queries all 60 patterns per event, `.iter_merged()` only one.
+    assert!(iter_calls >= 600 * 60);
+    assert!(merged_calls <= 60 + 600);
+}
+
+#[test]
+fn union_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 1, 3).at(0, 0, 0, 0));
+    let instants = || (-20..200).map(|i| start + (i * 13).minutes());
+
+    check_pattern(
+        &union([hourly(5), minutely(45), hourly(3)]),
+        range,
+        instants(),
+    )
+    .unwrap();
+    check_pattern(
+        &union([cron().minute(10), cron().hour(7)]),
+        range,
+        instants(),
+    )
+    .unwrap();
+    check_pattern(&Union::<Cron>::default(), range, instants()).unwrap();
+}
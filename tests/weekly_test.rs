@@ -0,0 +1,145 @@
+mod common;
+
+use common::{series_take, series_take_rev};
+use jiff::{
+    ToSpan,
+    civil::{Weekday, date, time},
+};
+use pretty_assertions::assert_eq;
+use recurring::pattern::{check_pattern, weekly};
+use recurring::{DateTimeRange, Event, Series};
+
+#[test]
+fn weekly_defaults() {
+    // 2025-01-01 is a Wednesday.
+    let start = date(2025, 1, 1).at(8, 30, 0, 0);
+    let end = date(2026, 1, 1).at(0, 0, 0, 0);
+
+    assert_eq!(
+        series_take(start..end, weekly(1), 3),
+        vec![
+            Event::at(date(2025, 1, 1).at(8, 30, 0, 0)),
+            Event::at(date(2025, 1, 8).at(8, 30, 0, 0)),
+            Event::at(date(2025, 1, 15).at(8, 30, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, weekly(3), 2),
+        vec![
+            Event::at(date(2025, 12, 24).at(8, 30, 0, 0)),
+            Event::at(date(2025, 12, 3).at(8, 30, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn weekly_on_weekdays() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 3, 1).at(0, 0, 0, 0);
+    let pattern = weekly(2)
+        .on([Weekday::Tuesday, Weekday::Thursday])
+        .at(time(10, 0, 0, 0));
+
+    assert_eq!(
+        series_take(start..end, pattern.clone(), 5),
+        vec![
+            Event::at(date(2025, 1, 2).at(10, 0, 0, 0)),
+            Event::at(date(2025, 1, 14).at(10, 0, 0, 0)),
+            Event::at(date(2025, 1, 16).at(10, 0, 0, 0)),
+            Event::at(date(2025, 1, 28).at(10, 0, 0, 0)),
+            Event::at(date(2025, 1, 30).at(10, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take_rev(start..end, pattern.clone(), 3),
+        vec![
+            Event::at(date(2025, 2, 27).at(10, 0, 0, 0)),
+            Event::at(date(2025, 2, 25).at(10, 0, 0, 0)),
+            Event::at(date(2025, 2, 13).at(10, 0, 0, 0)),
+        ]
+    );
+
+    let series = Series::new(start..end, pattern);
+    assert_eq!(
+        series.get_closest_to(date(2025, 1, 21).at(0, 0, 0, 0)),
+        Some(Event::at(date(2025, 1, 16).at(10, 0, 0, 0)))
+    );
+    assert_eq!(
+        series.get_closest_to(date(2025, 1, 23).at(0, 0, 0, 0)),
+        Some(Event::at(date(2025, 1, 28).at(10, 0, 0, 0)))
+    );
+}
+
+#[test]
+fn weekly_fixpoint() {
+    // The series starts in a week without events relative to the fixpoint.
+    let series = Series::builder(
+        date(2025, 1, 8).at(0, 0, 0, 0)..,
+        weekly(2).on([Weekday::Monday]),
+    )
+    .fixpoint(date(2024, 12, 31).at(9, 0, 0, 0))
+    .build()
+    .unwrap();
+
+    let events: Vec<Event> = series.iter().take(2).collect();
+    assert_eq!(
+        events,
+        vec![
+            Event::at(date(2025, 1, 13).at(9, 0, 0, 0)),
+            Event::at(date(2025, 1, 27).at(9, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn weekly_week_start() {
+    let start = date(2025, 1, 1).at(0, 0, 0, 0);
+    let end = date(2025, 2, 1).at(0, 0, 0, 0);
+    let pattern = |week_start| {
+        weekly(2)
+            .on([Weekday::Sunday, Weekday::Monday])
+            .at(time(12, 0, 0, 0))
+            .week_start(week_start)
+    };
+
+    assert_eq!(
+        series_take(start..end, pattern(Weekday::Monday), 3),
+        vec![
+            Event::at(date(2025, 1, 5).at(12, 0, 0, 0)),
+            Event::at(date(2025, 1, 13).at(12, 0, 0, 0)),
+            Event::at(date(2025, 1, 19).at(12, 0, 0, 0)),
+        ]
+    );
+    assert_eq!(
+        series_take(start..end, pattern(Weekday::Sunday), 3),
+        vec![
+            Event::at(date(2025, 1, 12).at(12, 0, 0, 0)),
+            Event::at(date(2025, 1, 13).at(12, 0, 0, 0)),
+            Event::at(date(2025, 1, 26).at(12, 0, 0, 0)),
+        ]
+    );
+}
+
+#[test]
+fn weekly_conformance() {
+    let start = date(2025, 1, 1).at(1, 0, 0, 0);
+    let range = DateTimeRange::from(start..date(2025, 3, 1).at(0, 0, 0, 0));
+    let instants = || (-20..600).map(|i| start + (i * 137).minutes());
+
+    check_pattern(&weekly(1), range, instants()).unwrap();
+    check_pattern(
+        &weekly(3).on([Weekday::Monday, Weekday::Saturday]),
+        range,
+        instants(),
+    )
+    .unwrap();
+    check_pattern(
+        &weekly(2)
+            .on([Weekday::Wednesday, Weekday::Sunday])
+            .at(time(0, 30, 0, 0))
+            .week_start(Weekday::Thursday),
+        range,
+        instants(),
+    )
+    .unwrap();
+}